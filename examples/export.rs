@@ -0,0 +1,33 @@
+use zahir::color::*;
+use zahir::geometry::*;
+use zahir::noise::*;
+use zahir::random::*;
+use zahir::render::*;
+
+fn main() {
+    let seed = Seed::from_entropy();
+    let perlin: PerlinNode<2, Wyhash> = PerlinNode::new(&seed);
+    let harmonic = HarmonicNode::new(&perlin, 8, 0.8, 2.0);
+    let sigmoid = SigmoidNode::new(&harmonic, -2.0);
+    let hypersphere = HypersphereNode::<2, EuclideanMetric>::new(0.2);
+    let noise_gen = SoftLightNode::new(&sigmoid, &hypersphere);
+
+    let viewport = Viewport::with_scale(RealPoint::new([0.0, 0.0]), 80.0, 800, 800);
+
+    let ramp = ColorRamp::with_blend(vec![
+        ColorStop::new(0.00, [0x0B, 0x1D, 0x51]),
+        ColorStop::new(0.45, [0x2E, 0x6F, 0xB5]),
+        ColorStop::new(0.50, [0xE8, 0xD8, 0x9C]),
+        ColorStop::new(0.55, [0x5D, 0x9C, 0x3F]),
+        ColorStop::new(0.75, [0x3B, 0x5E, 0x2B]),
+        ColorStop::new(0.90, [0x8C, 0x80, 0x74]),
+        ColorStop::new(1.00, [0xFF, 0xFF, 0xFF]),
+    ], ColorBlend::Oklab);
+
+    let color_gen = ColorNode::new(&noise_gen, &ramp);
+
+    save_grayscale(&noise_gen, &viewport, "noise.pgm").unwrap();
+    save_color_node(&color_gen, &viewport, "noise-color.png").unwrap();
+
+    println!("Seed: {}", seed.to_base58());
+}
@@ -27,7 +27,7 @@ fn main() {
 
     for y in 0..height {
         for x in 0..width {
-            let idx = y * height + x;
+            let idx = y * width + x;
 
             let x = (x as f64) / 80.0;
             let y = (y as f64) / 80.0;
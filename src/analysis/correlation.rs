@@ -0,0 +1,120 @@
+use crate::{analysis::SampleRegion, geometry::RealPoint, noise::NoiseNode};
+
+const DECORRELATION_THRESHOLD: f64 = 1.0 / std::f64::consts::E;
+
+// Normalized autocorrelation of a sampled region along a single axis, at a lag measured in samples
+pub fn autocorrelation<const DIM: usize>(
+    region: &SampleRegion<DIM>,
+    samples: &[f64],
+    dim: usize,
+    lag: usize,
+) -> f64 {
+    let num_samples = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / num_samples;
+    let variance = samples.iter().map(|&sample| (sample - mean).powi(2)).sum::<f64>() / num_samples;
+
+    let length = region.extent()[dim];
+    let stride = region.stride(dim);
+
+    let mut covariance = 0.0f64;
+    let mut num_pairs = 0usize;
+
+    for (idx, &sample) in samples.iter().enumerate() {
+        if (idx / stride) % length + lag < length {
+            covariance += (sample - mean) * (samples[idx + lag * stride] - mean);
+            num_pairs += 1;
+        };
+    };
+
+    if num_pairs == 0 || variance == 0.0 {
+        0.0
+    } else {
+        covariance / (num_pairs as f64) / variance
+    }
+}
+
+// Distance at which the autocorrelation first falls below 1/e, averaged across all axes and
+// measured in the same units as the region's spacing. Axes which never decorrelate within half of
+// their extent contribute that half-extent instead.
+pub fn autocorrelation_length<const DIM: usize, T>(node: &T, region: &SampleRegion<DIM>) -> f64
+where T: NoiseNode<DIM> {
+    let samples = region.sample(node);
+
+    let total: f64 = (0..DIM).map(|dim| {
+        let max_lag = (region.extent()[dim] / 2).max(1);
+        let mut previous = 1.0f64;
+
+        for lag in 1..=max_lag {
+            let current = autocorrelation(region, &samples, dim, lag);
+
+            if current < DECORRELATION_THRESHOLD {
+                let bias = (previous - DECORRELATION_THRESHOLD) / (previous - current);
+
+                return ((lag - 1) as f64) + bias;
+            };
+
+            previous = current;
+        };
+
+        max_lag as f64
+    }).sum();
+
+    total / (DIM as f64) * region.spacing()
+}
+
+// Ratio of the mean squared difference between samples separated by `lag` along the diagonals to
+// the same measure along the axes. Values close to 1.0 indicate an isotropic node; noise built on
+// an axis-aligned lattice typically drifts away from it.
+pub fn isotropy<const DIM: usize, T>(node: &T, region: &SampleRegion<DIM>, lag: f64) -> f64
+where T: NoiseNode<DIM> {
+    if DIM < 2 {
+        return 1.0;
+    };
+
+    let axes: Vec<RealPoint<DIM>> = (0..DIM).map(|dim| {
+        let mut coordinates = [0.0f64; DIM];
+        coordinates[dim] = 1.0;
+
+        RealPoint::new(coordinates)
+    }).collect();
+
+    let diagonal_scale = 1.0 / (DIM as f64).sqrt();
+
+    let diagonals: Vec<RealPoint<DIM>> = (0..(1usize << (DIM - 1))).map(|mask| {
+        let mut coordinates = [diagonal_scale; DIM];
+
+        for (dim, coordinate) in coordinates.iter_mut().enumerate().skip(1) {
+            if mask & (1 << (dim - 1)) > 0 {
+                *coordinate = -diagonal_scale;
+            };
+        };
+
+        RealPoint::new(coordinates)
+    }).collect();
+
+    let axis_variance = directional_variance(node, region, &axes, lag);
+    let diagonal_variance = directional_variance(node, region, &diagonals, lag);
+
+    diagonal_variance / axis_variance
+}
+
+fn directional_variance<const DIM: usize, T>(
+    node: &T,
+    region: &SampleRegion<DIM>,
+    directions: &[RealPoint<DIM>],
+    lag: f64,
+) -> f64 where T: NoiseNode<DIM> {
+    let mut total = 0.0f64;
+    let mut count = 0usize;
+
+    for point in region.points() {
+        let value = node.value_at(point);
+
+        for &direction in directions {
+            total += (node.value_at(point + direction * lag) - value).powi(2);
+            count += 1;
+        };
+    };
+
+    total / (count as f64)
+}
@@ -0,0 +1,211 @@
+use std::ops::{Add, Mul, Sub};
+
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    pub const ZERO: Self = Self::new(0.0, 0.0);
+
+    pub const fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    pub fn from_polar(magnitude: f64, phase: f64) -> Self {
+        let (sin, cos) = phase.sin_cos();
+
+        Self { re: magnitude * cos, im: magnitude * sin }
+    }
+
+    pub fn norm_sqr(self) -> f64 {
+        self.re.mul_add(self.re, self.im * self.im)
+    }
+
+    pub fn conj(self) -> Self {
+        Self { re: self.re, im: -self.im }
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self { re: self.re + rhs.re, im: self.im + rhs.im }
+    }
+}
+
+impl Sub for Complex {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self { re: self.re - rhs.re, im: self.im - rhs.im }
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            re: self.re.mul_add(rhs.re, -self.im * rhs.im),
+            im: self.re.mul_add(rhs.im, self.im * rhs.re),
+        }
+    }
+}
+
+impl Mul<f64> for Complex {
+    type Output = Self;
+
+    fn mul(self, scalar: f64) -> Self {
+        Self { re: self.re * scalar, im: self.im * scalar }
+    }
+}
+
+// In-place iterative radix-2 Cooley-Tukey transform; the inverse transform is normalized so that
+// `fft` followed by `ifft` is the identity
+pub fn fft(data: &mut [Complex]) {
+    transform(data, false);
+}
+
+pub fn ifft(data: &mut [Complex]) {
+    transform(data, true);
+}
+
+// Transforms a `width * height` buffer laid out in row-major order
+pub fn fft_2d(data: &mut [Complex], width: usize, height: usize) {
+    transform_2d(data, width, height, false);
+}
+
+pub fn ifft_2d(data: &mut [Complex], width: usize, height: usize) {
+    transform_2d(data, width, height, true);
+}
+
+fn transform(data: &mut [Complex], inverse: bool) {
+    let len = data.len();
+
+    assert!(len.is_power_of_two(), "FFT length must be a power of two, got {}", len);
+
+    let mut j = 0usize;
+
+    for i in 1..len {
+        let mut bit = len >> 1;
+
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        };
+
+        j |= bit;
+
+        if i < j {
+            data.swap(i, j);
+        };
+    };
+
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut span = 2usize;
+
+    while span <= len {
+        let step = Complex::from_polar(1.0, sign * std::f64::consts::TAU / (span as f64));
+
+        for start in (0..len).step_by(span) {
+            let mut twiddle = Complex::new(1.0, 0.0);
+
+            for offset in 0..(span / 2) {
+                let even = data[start + offset];
+                let odd = data[start + offset + span / 2] * twiddle;
+
+                data[start + offset] = even + odd;
+                data[start + offset + span / 2] = even - odd;
+
+                twiddle = twiddle * step;
+            };
+        };
+
+        span <<= 1;
+    };
+
+    if inverse {
+        let scale = 1.0 / (len as f64);
+
+        for value in data.iter_mut() {
+            *value = *value * scale;
+        };
+    };
+}
+
+fn transform_2d(data: &mut [Complex], width: usize, height: usize, inverse: bool) {
+    assert_eq!(data.len(), width * height);
+
+    for row in data.chunks_exact_mut(width) {
+        transform(row, inverse);
+    };
+
+    let mut column = vec![Complex::ZERO; height];
+
+    for x in 0..width {
+        for (y, value) in column.iter_mut().enumerate() {
+            *value = data[y * width + x];
+        };
+
+        transform(&mut column, inverse);
+
+        for (y, value) in column.iter().enumerate() {
+            data[y * width + x] = *value;
+        };
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::{fft, fft_2d, ifft, ifft_2d, Complex};
+
+    fn assert_close(lhs: &[Complex], rhs: &[Complex]) {
+        for (a, b) in lhs.iter().zip(rhs.iter()) {
+            assert!((a.re - b.re).abs() < 1e-9 && (a.im - b.im).abs() < 1e-9, "{:?} != {:?}", a, b);
+        };
+    }
+
+    #[test]
+    fn impulse() {
+        let mut data = vec![Complex::ZERO; 8];
+        data[0] = Complex::new(1.0, 0.0);
+
+        fft(&mut data);
+
+        assert_close(&data, &[Complex::new(1.0, 0.0); 8]);
+    }
+
+    #[test]
+    fn single_frequency() {
+        let mut data: Vec<Complex> = (0..8).map(|idx| {
+            Complex::from_polar(1.0, std::f64::consts::TAU * (idx as f64) * 2.0 / 8.0)
+        }).collect();
+
+        fft(&mut data);
+
+        let mut expected = vec![Complex::ZERO; 8];
+        expected[2] = Complex::new(8.0, 0.0);
+
+        assert_close(&data, &expected);
+    }
+
+    #[test]
+    fn round_trip() {
+        let original: Vec<Complex> = (0..32).map(|idx| {
+            Complex::new((idx as f64 * 0.7).sin(), (idx as f64 * 1.3).cos())
+        }).collect();
+
+        let mut data = original.clone();
+
+        fft(&mut data);
+        ifft(&mut data);
+        assert_close(&data, &original);
+
+        fft_2d(&mut data, 8, 4);
+        ifft_2d(&mut data, 8, 4);
+        assert_close(&data, &original);
+    }
+}
@@ -0,0 +1,94 @@
+mod correlation;
+mod fft;
+mod region;
+mod spectrum;
+mod statistics;
+
+pub use correlation::{autocorrelation, autocorrelation_length, isotropy};
+pub use fft::{fft, fft_2d, ifft, ifft_2d, Complex};
+pub use region::SampleRegion;
+pub use spectrum::RadialSpectrum;
+pub use statistics::{Histogram, Summary};
+
+use crate::noise::NoiseNode;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct NoiseStatistics {
+    pub summary: Summary,
+    pub histogram: Histogram,
+    pub autocorrelation_length: f64,
+    pub isotropy: f64,
+}
+
+impl NoiseStatistics {
+    // Histogram bins span [0.0, 1.0], and isotropy is measured at a lag of one sample spacing
+    pub fn measure<const DIM: usize, T>(node: &T, region: &SampleRegion<DIM>, num_bins: usize) -> Self
+    where T: NoiseNode<DIM> {
+        let samples = region.sample(node);
+
+        Self {
+            summary: Summary::from_samples(&samples),
+            histogram: Histogram::new(&samples, num_bins, 0.0, 1.0),
+            autocorrelation_length: autocorrelation_length(node, region),
+            isotropy: isotropy(node, region, region.spacing()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        analysis::{NoiseStatistics, RadialSpectrum, SampleRegion},
+        geometry::{EuclideanMetric, RealPoint},
+        noise::{HarmonicNode, PerlinNode, WorleyNode, WorleyPaintMethod},
+        random::{Seed, Wyhash},
+    };
+
+    fn region_2d() -> SampleRegion<2> {
+        SampleRegion::new(RealPoint::new([0.3, 0.7]), 0.125, [64, 64])
+    }
+
+    #[test]
+    fn perlin_2d_statistics() {
+        let perlin: PerlinNode<2, Wyhash> = PerlinNode::new(&Seed::DEFAULT_SEED);
+        let stats = NoiseStatistics::measure(&perlin, &region_2d(), 10);
+
+        assert!(stats.summary.min >= 0.0 && stats.summary.max <= 1.0);
+        assert!((stats.summary.mean - 0.5).abs() < 0.1, "{:?}", stats.summary);
+        assert!(stats.summary.variance > 0.02 && stats.summary.variance < 0.12, "{:?}", stats.summary);
+        assert!(stats.autocorrelation_length > 0.2 && stats.autocorrelation_length < 0.8);
+        assert!((stats.isotropy - 1.0).abs() < 0.25, "{}", stats.isotropy);
+    }
+
+    #[test]
+    fn harmonic_statistics() {
+        let perlin: PerlinNode<2, Wyhash> = PerlinNode::new(&Seed::DEFAULT_SEED);
+        let harmonic = HarmonicNode::new(&perlin, 4, 0.5, 2.0);
+
+        let perlin_stats = NoiseStatistics::measure(&perlin, &region_2d(), 10);
+        let harmonic_stats = NoiseStatistics::measure(&harmonic, &region_2d(), 10);
+
+        // Summing decorrelated octaves narrows the distribution and shortens the correlation length
+        assert!(harmonic_stats.summary.variance < perlin_stats.summary.variance);
+        assert!(harmonic_stats.autocorrelation_length < perlin_stats.autocorrelation_length);
+
+        let perlin_spectrum = RadialSpectrum::measure(&perlin, &region_2d());
+        let harmonic_spectrum = RadialSpectrum::measure(&harmonic, &region_2d());
+
+        assert!(harmonic_spectrum.centroid() > perlin_spectrum.centroid());
+    }
+
+    #[test]
+    fn worley_statistics() {
+        let worley: WorleyNode<2, EuclideanMetric, Wyhash> = WorleyNode::new(
+            &Seed::DEFAULT_SEED,
+            WorleyPaintMethod::Value,
+        );
+
+        let stats = NoiseStatistics::measure(&worley, &region_2d(), 10);
+
+        assert!(stats.summary.min >= 0.0 && stats.summary.max < 1.0);
+        assert!((stats.summary.mean - 0.5).abs() < 0.15, "{:?}", stats.summary);
+        assert!(stats.histogram.counts().iter().all(|&count| count > 0));
+    }
+}
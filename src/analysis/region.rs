@@ -0,0 +1,57 @@
+use crate::{geometry::RealPoint, noise::NoiseNode};
+
+// A regular lattice of sample points, laid out in row-major order with the first dimension
+// varying fastest
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SampleRegion<const DIM: usize> {
+    origin: RealPoint<DIM>,
+    spacing: f64,
+    extent: [usize; DIM],
+}
+
+impl<const DIM: usize> SampleRegion<DIM> {
+    pub fn new(origin: RealPoint<DIM>, spacing: f64, extent: [usize; DIM]) -> Self {
+        Self { origin, spacing, extent }
+    }
+
+    pub fn origin(&self) -> RealPoint<DIM> {
+        self.origin
+    }
+
+    pub fn spacing(&self) -> f64 {
+        self.spacing
+    }
+
+    pub fn extent(&self) -> [usize; DIM] {
+        self.extent
+    }
+
+    pub fn num_samples(&self) -> usize {
+        self.extent.iter().product()
+    }
+
+    // Distance between consecutive samples along `dim` in the flattened sample buffer
+    pub fn stride(&self, dim: usize) -> usize {
+        self.extent[..dim].iter().product()
+    }
+
+    pub fn point_at(&self, idx: usize) -> RealPoint<DIM> {
+        let mut remainder = idx;
+        let mut offsets = [0.0f64; DIM];
+
+        for (offset, &length) in offsets.iter_mut().zip(self.extent.iter()) {
+            *offset = (remainder % length) as f64;
+            remainder /= length;
+        };
+
+        self.origin + RealPoint::new(offsets) * self.spacing
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = RealPoint<DIM>> + '_ {
+        (0..self.num_samples()).map(|idx| self.point_at(idx))
+    }
+
+    pub fn sample<T: NoiseNode<DIM>>(&self, node: &T) -> Vec<f64> {
+        self.points().map(|point| node.value_at(point)).collect()
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{analysis::{fft_2d, Complex, SampleRegion}, noise::NoiseNode};
+
+// Power spectrum averaged over rings of equal radial wavenumber, where wavenumber `k` corresponds
+// to `k` cycles across the sampled region
+#[derive(Clone, PartialEq, Debug)]
+pub struct RadialSpectrum {
+    power: Vec<f64>,
+    fundamental: f64,
+}
+
+impl RadialSpectrum {
+    // Region extents must be powers of two
+    pub fn measure<T: NoiseNode<2>>(node: &T, region: &SampleRegion<2>) -> Self {
+        let [width, height] = region.extent();
+        let samples = region.sample(node);
+        let mean = samples.iter().sum::<f64>() / (samples.len() as f64);
+
+        let mut data: Vec<Complex> = samples.iter().map(|&sample| {
+            Complex::new(sample - mean, 0.0)
+        }).collect();
+
+        fft_2d(&mut data, width, height);
+
+        let num_bins = width.min(height) / 2 + 1;
+        let mut power = vec![0.0f64; num_bins];
+        let mut counts = vec![0usize; num_bins];
+
+        let normalization = (width * height) as f64;
+
+        for (idx, value) in data.iter().enumerate() {
+            let kx = signed_frequency(idx % width, width) * (width.min(height) as f64) / (width as f64);
+            let ky = signed_frequency(idx / width, height) * (width.min(height) as f64) / (height as f64);
+            let bin = kx.hypot(ky).round() as usize;
+
+            if bin < num_bins {
+                power[bin] += value.norm_sqr() / normalization;
+                counts[bin] += 1;
+            };
+        };
+
+        for (bin_power, &count) in power.iter_mut().zip(counts.iter()) {
+            if count > 0 {
+                *bin_power /= count as f64;
+            };
+        };
+
+        let fundamental = 1.0 / ((width.min(height) as f64) * region.spacing());
+
+        Self { power, fundamental }
+    }
+
+    pub fn power(&self) -> &[f64] {
+        &self.power
+    }
+
+    // Spatial frequency of a bin, in cycles per unit distance
+    pub fn frequency(&self, bin: usize) -> f64 {
+        (bin as f64) * self.fundamental
+    }
+
+    // Frequency around which the spectral power is centred, ignoring the DC bin
+    pub fn centroid(&self) -> f64 {
+        let (weighted, total) = self.power.iter().enumerate().skip(1).fold(
+            (0.0f64, 0.0f64),
+            |(weighted, total), (bin, &power)| (weighted + self.frequency(bin) * power, total + power),
+        );
+
+        weighted / total
+    }
+}
+
+fn signed_frequency(idx: usize, length: usize) -> f64 {
+    if idx <= length / 2 {
+        idx as f64
+    } else {
+        (idx as f64) - (length as f64)
+    }
+}
@@ -0,0 +1,125 @@
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Summary {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub variance: f64,
+}
+
+impl Summary {
+    pub fn from_samples(samples: &[f64]) -> Self {
+        let num_samples = samples.len() as f64;
+
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
+        let mut sum = 0.0f64;
+
+        for &sample in samples {
+            min = min.min(sample);
+            max = max.max(sample);
+            sum += sample;
+        };
+
+        let mean = sum / num_samples;
+        let variance = samples.iter().map(|&sample| (sample - mean).powi(2)).sum::<f64>()
+            / num_samples;
+
+        Self { min, max, mean, variance }
+    }
+
+    pub fn std_dev(&self) -> f64 {
+        self.variance.sqrt()
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Histogram {
+    min: f64,
+    max: f64,
+    counts: Vec<usize>,
+}
+
+impl Histogram {
+    // Samples outside of [min, max] are clamped into the first and last bins
+    pub fn new(samples: &[f64], num_bins: usize, min: f64, max: f64) -> Self {
+        assert!(num_bins > 0, "Histogram must have at least one bin");
+
+        let mut histogram = Self { min, max, counts: vec![0; num_bins] };
+
+        for &sample in samples {
+            let bin = histogram.bin_for(sample);
+
+            histogram.counts[bin] += 1;
+        };
+
+        histogram
+    }
+
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    pub fn num_bins(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn bin_width(&self) -> f64 {
+        (self.max - self.min) / (self.num_bins() as f64)
+    }
+
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    // Fraction of all samples falling within each bin
+    pub fn frequencies(&self) -> Vec<f64> {
+        let total = self.total() as f64;
+
+        self.counts.iter().map(|&count| (count as f64) / total).collect()
+    }
+
+    pub fn bin_for(&self, value: f64) -> usize {
+        let bin = ((value - self.min) / self.bin_width()).floor();
+
+        bin.clamp(0.0, (self.num_bins() - 1) as f64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::{Histogram, Summary};
+
+    #[test]
+    fn summary() {
+        let summary = Summary::from_samples(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+
+        assert_eq!(summary.min, 2.0);
+        assert_eq!(summary.max, 9.0);
+        assert_eq!(summary.mean, 5.0);
+        assert_eq!(summary.variance, 4.0);
+        assert_eq!(summary.std_dev(), 2.0);
+    }
+
+    #[test]
+    fn histogram() {
+        let histogram = Histogram::new(&[-0.5, 0.0, 0.1, 0.3, 0.5, 0.55, 0.99, 1.0, 1.5], 4, 0.0, 1.0);
+
+        assert_eq!(histogram.bin_width(), 0.25);
+        assert_eq!(histogram.counts(), &[3, 1, 2, 3]);
+        assert_eq!(histogram.total(), 9);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one bin")]
+    fn histogram_without_bins() {
+        Histogram::new(&[0.5], 0, 0.0, 1.0);
+    }
+}
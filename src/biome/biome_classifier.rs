@@ -0,0 +1,192 @@
+use std::marker::PhantomData;
+use crate::{
+    collections::{byte_width, EnumBitSet, EnumIndex},
+    geometry::{DistanceMetric, RealPoint},
+    noise::VectorNoiseNode,
+};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum BiomeRegion<const N: usize> {
+    // Inclusive bounds along every channel
+    Box { min: RealPoint<N>, max: RealPoint<N> },
+    Centroid(RealPoint<N>),
+}
+
+impl<const N: usize> BiomeRegion<N> {
+    // Zero anywhere inside a box
+    pub fn distance<T: DistanceMetric>(&self, point: RealPoint<N>) -> f64 {
+        match *self {
+            BiomeRegion::Box { min, max } => {
+                let mut offset = RealPoint::new([0.0; N]);
+
+                for axis in 0..N {
+                    offset[axis] = (min[axis] - point[axis]).max(point[axis] - max[axis]).max(0.0);
+                };
+
+                offset.magnitude::<T>()
+            },
+
+            BiomeRegion::Centroid(center) => (point - center).magnitude::<T>(),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Classification<T> {
+    pub biome: T,
+    // Normalized weights of every biome within the blend distance, heaviest first; the classified
+    // biome is always the first entry
+    pub weights: Vec<(T, f64)>,
+}
+
+// Whittaker-style classification over `N` channels, such as elevation, temperature and moisture.
+// Each point belongs to the region nearest to it under the metric, so boxes claim everything they
+// contain, and ties go to the region registered first. Biomes whose regions are within
+// `blend_distance` of the nearest one share the blend weights, falling off linearly.
+#[derive(Clone, PartialEq, Debug)]
+pub struct BiomeClassifier<T, const N: usize, U: DistanceMetric> {
+    regions: Vec<(T, BiomeRegion<N>)>,
+    blend_distance: f64,
+    phantom: PhantomData<U>,
+}
+
+impl<T, const N: usize, U> BiomeClassifier<T, N, U>
+where T: Copy + PartialEq + EnumIndex, U: DistanceMetric {
+    pub fn new(blend_distance: f64) -> Self {
+        Self { regions: Vec::new(), blend_distance, phantom: PhantomData }
+    }
+
+    pub fn with_box(mut self, biome: T, min: RealPoint<N>, max: RealPoint<N>) -> Self {
+        self.regions.push((biome, BiomeRegion::Box { min, max }));
+        self
+    }
+
+    pub fn with_centroid(mut self, biome: T, center: RealPoint<N>) -> Self {
+        self.regions.push((biome, BiomeRegion::Centroid(center)));
+        self
+    }
+
+    pub fn regions(&self) -> &[(T, BiomeRegion<N>)] {
+        &self.regions
+    }
+
+    pub fn classify(&self, channels: RealPoint<N>) -> Option<Classification<T>> {
+        self.classify_filtered(channels, |_| true)
+    }
+
+    // Only considers biomes included in `allowed`
+    pub fn classify_masked(&self, channels: RealPoint<N>, allowed: &EnumBitSet<T>) -> Option<Classification<T>>
+    where [(); byte_width::<T>()]: {
+        self.classify_filtered(channels, |biome| allowed.include(biome))
+    }
+
+    // Classifies the channels produced by `node` at `point`
+    pub fn classify_at<V, const DIM: usize>(&self, node: &V, point: RealPoint<DIM>) -> Option<Classification<T>>
+    where V: VectorNoiseNode<DIM, N> {
+        self.classify(node.vector_at(point))
+    }
+
+    fn classify_filtered<F>(&self, channels: RealPoint<N>, is_allowed: F) -> Option<Classification<T>>
+    where F: Fn(T) -> bool {
+        let distances: Vec<(T, f64)> = self.regions.iter()
+            .filter(|&&(biome, _)| is_allowed(biome))
+            .map(|&(biome, region)| (biome, region.distance::<U>(channels)))
+            .collect();
+
+        let &(biome, nearest) = distances.iter()
+            .fold(None, |best: Option<&(T, f64)>, candidate| match best {
+                Some(best) if best.1 <= candidate.1 => Some(best),
+                _ => Some(candidate),
+            })?;
+
+        // Each biome is weighted by its nearest region only
+        let mut weights: Vec<(T, f64)> = vec![(biome, 1.0)];
+
+        for &(candidate, distance) in &distances {
+            let weight = if self.blend_distance > 0.0 {
+                1.0 - (distance - nearest) / self.blend_distance
+            } else {
+                0.0
+            };
+
+            if weight <= 0.0 {
+                continue;
+            };
+
+            match weights.iter_mut().find(|(existing, _)| *existing == candidate) {
+                Some((_, existing_weight)) => *existing_weight = existing_weight.max(weight),
+                None => weights.push((candidate, weight)),
+            };
+        };
+
+        let total: f64 = weights.iter().map(|&(_, weight)| weight).sum();
+
+        for (_, weight) in weights.iter_mut() {
+            *weight /= total;
+        };
+
+        // Stable, so the classified biome stays first when tied
+        weights.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+
+        Some(Classification { biome, weights })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use enum_map::Enum;
+    use crate::{
+        biome::BiomeClassifier,
+        collections::EnumBitSet,
+        enum_bit_set,
+        geometry::{EuclideanMetric, RealPoint},
+    };
+
+    #[derive(Copy, Clone, PartialEq, Eq, Debug, Enum)]
+    enum Biome {
+        Desert,
+        Forest,
+        Rainforest,
+        Tundra,
+    }
+
+    // Channels are temperature and moisture
+    fn classifier() -> BiomeClassifier<Biome, 2, EuclideanMetric> {
+        BiomeClassifier::new(0.2)
+            .with_box(Biome::Tundra, RealPoint::new([0.0, 0.0]), RealPoint::new([0.2, 1.0]))
+            .with_centroid(Biome::Desert, RealPoint::new([0.8, 0.1]))
+            .with_centroid(Biome::Forest, RealPoint::new([0.5, 0.6]))
+            .with_centroid(Biome::Rainforest, RealPoint::new([0.9, 0.9]))
+    }
+
+    #[test]
+    fn classify() {
+        let classifier = classifier();
+
+        let tundra = classifier.classify(RealPoint::new([0.1, 0.5])).unwrap();
+
+        assert_eq!(tundra.biome, Biome::Tundra);
+        assert_eq!(tundra.weights, vec![(Biome::Tundra, 1.0)]);
+
+        assert_eq!(classifier.classify(RealPoint::new([0.85, 0.0])).unwrap().biome, Biome::Desert);
+        assert_eq!(classifier.classify(RealPoint::new([0.5, 0.5])).unwrap().biome, Biome::Forest);
+
+        // Halfway between two centroids, both biomes are blended equally
+        let blended = classifier.classify(RealPoint::new([0.7, 0.75])).unwrap();
+
+        assert_eq!(blended.weights.len(), 2);
+        assert!((blended.weights[0].1 - 0.5).abs() < 1e-9);
+        assert!((blended.weights[1].1 - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn classify_masked() {
+        let classifier = classifier();
+        let point = RealPoint::new([0.85, 0.0]);
+
+        let mask: EnumBitSet<Biome> = enum_bit_set![Biome::Forest, Biome::Rainforest; true];
+
+        assert_eq!(classifier.classify_masked(point, &mask).unwrap().biome, Biome::Forest);
+        assert!(classifier.classify_masked(point, &EnumBitSet::none()).is_none());
+    }
+}
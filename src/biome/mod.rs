@@ -0,0 +1,3 @@
+mod biome_classifier;
+
+pub use biome_classifier::{BiomeClassifier, BiomeRegion, Classification};
@@ -0,0 +1,173 @@
+use crate::{cellular::Rule, collections::Grid, geometry::Point, noise::NoiseNode, random::ExtendedRng};
+
+// Live cells where `node`, sampled at each cell's centre, is below `threshold`. With a `TileNode`,
+// this fills each cell independently with probability `threshold`.
+pub fn threshold_fill<T, const DIM: usize>(size: [usize; DIM], node: &T, threshold: f64) -> Grid<bool, DIM>
+where T: NoiseNode<DIM> {
+    Grid::from_fn(size, |point| node.value_at(point.to_real_point() + 0.5) < threshold)
+}
+
+// Live cells with probability `fill_ratio`, clamped onto [0.0, 1.0]
+pub fn random_fill<R, const DIM: usize>(size: [usize; DIM], fill_ratio: f64, rng: &mut R) -> Grid<bool, DIM>
+where R: ExtendedRng + ?Sized {
+    let fill_ratio = fill_ratio.clamp(0.0, 1.0);
+
+    Grid::from_fn(size, |_| rng.round::<f64, u8>(fill_ratio) == Some(1))
+}
+
+// A number of generations run under one rule, or under several with each cell choosing its rule by
+// sampling `selector` at `point * selector_scale`: values in [0.0, 1.0) are split evenly between
+// the rules in order.
+pub struct Phase<'a, const DIM: usize> {
+    rules: Vec<Rule>,
+    selector: Option<(&'a dyn NoiseNode<DIM>, f64)>,
+    generations: usize,
+}
+
+impl<'a, const DIM: usize> Phase<'a, DIM> {
+    pub fn new(rule: Rule, generations: usize) -> Self {
+        Self { rules: vec![rule], selector: None, generations }
+    }
+
+    pub fn with_selector(
+        rules: Vec<Rule>,
+        selector: &'a dyn NoiseNode<DIM>,
+        selector_scale: f64,
+        generations: usize,
+    ) -> Self {
+        assert!(!rules.is_empty(), "A phase requires at least one rule");
+
+        Self { rules, selector: Some((selector, selector_scale)), generations }
+    }
+
+    fn rule_at(&self, point: Point<DIM>) -> &Rule {
+        match self.selector {
+            None => &self.rules[0],
+
+            Some((selector, scale)) => {
+                let value = selector.value_at(point.to_real_point() * scale);
+                let idx = (value * self.rules.len() as f64).floor().max(0.0) as usize;
+
+                &self.rules[idx.min(self.rules.len() - 1)]
+            },
+        }
+    }
+}
+
+// Runs phases of Moore-neighbourhood automata in order. Cells beyond the grid count as alive when
+// `outside_alive` is set, which closes caves off at the edges when live cells are walls.
+pub struct CellularAutomaton<'a, const DIM: usize> {
+    phases: Vec<Phase<'a, DIM>>,
+    outside_alive: bool,
+}
+
+impl<'a, const DIM: usize> CellularAutomaton<'a, DIM> {
+    pub fn new(outside_alive: bool) -> Self {
+        Self { phases: Vec::new(), outside_alive }
+    }
+
+    pub fn with_phase(mut self, phase: Phase<'a, DIM>) -> Self {
+        self.phases.push(phase);
+        self
+    }
+
+    pub fn run(&self, initial: &Grid<bool, DIM>) -> Grid<bool, DIM> {
+        let mut cells = initial.clone();
+
+        for phase in &self.phases {
+            for _ in 0..phase.generations {
+                cells = self.step(&cells, phase);
+            };
+        };
+
+        cells
+    }
+
+    fn step(&self, cells: &Grid<bool, DIM>, phase: &Phase<'a, DIM>) -> Grid<bool, DIM> {
+        Grid::from_fn(cells.size(), |point| {
+            let count = point.neighbors()
+                .filter(|&neighbor| cells.get(neighbor).copied().unwrap_or(self.outside_alive))
+                .count();
+
+            phase.rule_at(point).next_state(cells[point], count)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_chacha::ChaCha8Rng;
+    use crate::{
+        cellular::{random_fill, threshold_fill, CellularAutomaton, Phase, Rule},
+        collections::Grid,
+        geometry::{Point, RealPoint},
+        noise::{NoiseNode, TileNode},
+        random::{Seed, Seedable, Wyhash},
+    };
+
+    #[test]
+    fn blinker() {
+        let life = Rule::parse("B3/S23").unwrap();
+        let automaton = CellularAutomaton::new(false).with_phase(Phase::new(life, 1));
+
+        let horizontal = Grid::from_fn([5, 5], |point| point[1] == 2 && (1..4).contains(&point[0]));
+        let vertical = Grid::from_fn([5, 5], |point| point[0] == 2 && (1..4).contains(&point[1]));
+
+        assert_eq!(automaton.run(&horizontal), vertical);
+        assert_eq!(automaton.run(&vertical), horizontal);
+    }
+
+    #[test]
+    fn caves() {
+        let seed = Seed::DEFAULT_SEED;
+        let tiles: TileNode<2, Wyhash> = TileNode::new(&seed);
+
+        let initial = threshold_fill([48, 32], &tiles, 0.45);
+        let automaton = CellularAutomaton::new(true)
+            .with_phase(Phase::new(Rule::parse("B5678/S45678").unwrap(), 4))
+            .with_phase(Phase::new(Rule::parse("B5678/S5678").unwrap(), 2));
+
+        let caves = automaton.run(&initial);
+        let walls = caves.values().iter().filter(|&&wall| wall).count();
+
+        assert_eq!(caves, automaton.run(&initial));
+        assert!(walls > 0 && walls < caves.len());
+    }
+
+    #[test]
+    fn fill_ratio() {
+        let mut rng = ChaCha8Rng::from_seed(&Seed::DEFAULT_SEED);
+
+        for fill_ratio in [0.2, 0.45, 0.8] {
+            let cells = random_fill([64, 64], fill_ratio, &mut rng);
+            let live = cells.values().iter().filter(|&&live| live).count() as f64 / cells.len() as f64;
+
+            assert_eq!(cells.size(), [64, 64]);
+            assert!((live - fill_ratio).abs() < 0.03, "{}: {}", fill_ratio, live);
+        };
+
+        assert!(random_fill([16, 16], 0.0, &mut rng).values().iter().all(|&live| !live));
+        assert!(random_fill([16, 16], 1.0, &mut rng).values().iter().all(|&live| live));
+        assert!(random_fill([16, 16], 1.5, &mut rng).values().iter().all(|&live| live));
+    }
+
+    #[test]
+    fn rule_selection() {
+        struct SplitNode;
+
+        impl NoiseNode<2> for SplitNode {
+            fn value_at(&self, point: RealPoint<2>) -> f64 {
+                if point[0] < 5.0 { 0.0 } else { 0.9 }
+            }
+        }
+
+        let rules = vec![Rule::parse("B/S").unwrap(), Rule::parse("B012345678/S012345678").unwrap()];
+        let automaton = CellularAutomaton::new(false)
+            .with_phase(Phase::with_selector(rules, &SplitNode, 1.0, 1));
+
+        let cells = automaton.run(&Grid::new([10, 4], true));
+
+        assert!(!cells[Point::new([4, 0])]);
+        assert!(cells[Point::new([5, 3])]);
+    }
+}
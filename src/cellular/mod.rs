@@ -0,0 +1,5 @@
+mod cellular_automaton;
+mod rule;
+
+pub use cellular_automaton::{CellularAutomaton, Phase, random_fill, threshold_fill};
+pub use rule::{ParseRuleError, Rule};
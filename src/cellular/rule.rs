@@ -0,0 +1,134 @@
+use std::str::FromStr;
+
+// Outer-totalistic birth/survival rule. Counts above 127 are not representable, which covers the
+// Moore neighbourhoods of up to four dimensions.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Rule {
+    birth: u128,
+    survival: u128,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ParseRuleError {
+    InvalidFormat,
+    InvalidCharacter { character: char, index: usize },
+    CountTooLarge { count: usize },
+}
+
+impl Rule {
+    pub const MAX_COUNT: usize = 127;
+
+    pub fn new(birth: &[usize], survival: &[usize]) -> Self {
+        let mask = |counts: &[usize]| counts.iter().fold(0u128, |mask, &count| {
+            assert!(count <= Self::MAX_COUNT, "Neighbour count {} too large", count);
+
+            mask | (1 << count)
+        });
+
+        Self { birth: mask(birth), survival: mask(survival) }
+    }
+
+    // Parses "B<counts>/S<counts>" notation, e.g. "B3/S23" for Life or "B678/S345678" for caves.
+    // Counts are single digits, or comma separated when any exceeds nine, as in "B5,6,7/S10,11";
+    // a comma anywhere switches both parts to the separated form.
+    pub fn parse(s: &str) -> Result<Self, ParseRuleError> {
+        let (birth, survival) = s.split_once('/').ok_or(ParseRuleError::InvalidFormat)?;
+
+        let strip = |part: &str, prefix: char| -> Result<String, ParseRuleError> {
+            let mut chars = part.chars();
+
+            match chars.next() {
+                Some(first) if first.eq_ignore_ascii_case(&prefix) => Ok(chars.collect()),
+                _ => Err(ParseRuleError::InvalidFormat),
+            }
+        };
+
+        let is_separated = s.contains(',');
+
+        let birth_counts = parse_counts(&strip(birth, 'B')?, 1, is_separated)?;
+        let survival_counts = parse_counts(&strip(survival, 'S')?, birth.len() + 2, is_separated)?;
+
+        Ok(Self::new(&birth_counts, &survival_counts))
+    }
+
+    pub fn is_birth(&self, count: usize) -> bool {
+        count <= Self::MAX_COUNT && (self.birth >> count) & 1 == 1
+    }
+
+    pub fn is_survival(&self, count: usize) -> bool {
+        count <= Self::MAX_COUNT && (self.survival >> count) & 1 == 1
+    }
+
+    pub fn next_state(&self, is_alive: bool, count: usize) -> bool {
+        if is_alive { self.is_survival(count) } else { self.is_birth(count) }
+    }
+}
+
+// `offset` is the index of the first character within the full rule string, for error reporting
+fn parse_counts(counts: &str, offset: usize, is_separated: bool) -> Result<Vec<usize>, ParseRuleError> {
+    if counts.is_empty() {
+        Ok(Vec::new())
+    } else if is_separated {
+        let mut index = offset;
+
+        counts.split(',').map(|count| {
+            let start = index;
+
+            index += count.len() + 1;
+
+            if let Some((char_index, character)) = count.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+                return Err(ParseRuleError::InvalidCharacter { character, index: start + char_index });
+            };
+
+            let count = count.parse().map_err(|_| ParseRuleError::InvalidFormat)?;
+
+            if count > Rule::MAX_COUNT {
+                Err(ParseRuleError::CountTooLarge { count })
+            } else {
+                Ok(count)
+            }
+        }).collect()
+    } else {
+        counts.char_indices().map(|(index, character)| {
+            character.to_digit(10)
+                .map(|digit| digit as usize)
+                .ok_or(ParseRuleError::InvalidCharacter { character, index: offset + index })
+        }).collect()
+    }
+}
+
+impl FromStr for Rule {
+    type Err = ParseRuleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cellular::{ParseRuleError, Rule};
+
+    #[test]
+    fn parse() {
+        let rule: Rule = "B678/S345678".parse().unwrap();
+
+        assert_eq!(rule, Rule::new(&[6, 7, 8], &[3, 4, 5, 6, 7, 8]));
+        assert!(rule.next_state(false, 6));
+        assert!(!rule.next_state(false, 5));
+        assert!(rule.next_state(true, 3));
+        assert!(!rule.next_state(true, 2));
+
+        assert_eq!(Rule::parse("b3/s23"), Ok(Rule::new(&[3], &[2, 3])));
+        assert_eq!(Rule::parse("B/S"), Ok(Rule::new(&[], &[])));
+        assert_eq!(Rule::parse("B13,14/S20"), Ok(Rule::new(&[13, 14], &[20])));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(Rule::parse("B3S23"), Err(ParseRuleError::InvalidFormat));
+        assert_eq!(Rule::parse("S23/B3"), Err(ParseRuleError::InvalidFormat));
+        assert_eq!(Rule::parse("B3/S2x"), Err(ParseRuleError::InvalidCharacter { character: 'x', index: 5 }));
+        assert_eq!(Rule::parse("B3,200/S2"), Err(ParseRuleError::CountTooLarge { count: 200 }));
+    }
+}
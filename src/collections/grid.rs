@@ -0,0 +1,269 @@
+use std::ops::{Index, IndexMut};
+use crate::{geometry::{Aabb, Point, RealPoint}, noise::NoiseNode};
+
+// Dense N-dimensional storage covering the cells from the origin up to, but excluding, `size`.
+// Values are laid out with the first axis varying fastest, so rows along it are contiguous.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Grid<T, const DIM: usize> {
+    size: [usize; DIM],
+    values: Vec<T>,
+}
+
+impl<T, const DIM: usize> Grid<T, DIM> {
+    pub fn new(size: [usize; DIM], value: T) -> Self where T: Clone {
+        Self { size, values: vec![value; size.iter().product()] }
+    }
+
+    pub fn from_fn<F>(size: [usize; DIM], mut f: F) -> Self where F: FnMut(Point<DIM>) -> T {
+        let len = size.iter().product();
+        let values = (0..len).map(|idx| f(point_at(size, idx))).collect();
+
+        Self { size, values }
+    }
+
+    // Takes ownership of `values`, which must be laid out in the grid's order
+    pub fn from_vec(size: [usize; DIM], values: Vec<T>) -> Self {
+        assert_eq!(values.len(), size.iter().product::<usize>(), "Value count does not match grid size");
+
+        Self { size, values }
+    }
+
+    pub fn size(&self) -> [usize; DIM] {
+        self.size
+    }
+
+    pub fn bounds(&self) -> Aabb<DIM> {
+        Aabb::with_size(Point::new([0; DIM]), self.size)
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn values_mut(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.values
+    }
+
+    pub fn contains(&self, point: Point<DIM>) -> bool {
+        contains(self.size, point)
+    }
+
+    pub fn index_of(&self, point: Point<DIM>) -> Option<usize> {
+        index_of(self.size, point)
+    }
+
+    pub fn point_at(&self, idx: usize) -> Point<DIM> {
+        point_at(self.size, idx)
+    }
+
+    pub fn get(&self, point: Point<DIM>) -> Option<&T> {
+        self.index_of(point).map(|idx| &self.values[idx])
+    }
+
+    pub fn get_mut(&mut self, point: Point<DIM>) -> Option<&mut T> {
+        self.index_of(point).map(|idx| &mut self.values[idx])
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point<DIM>> {
+        let size = self.size;
+
+        (0..self.values.len()).map(move |idx| point_at(size, idx))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point<DIM>, &T)> {
+        self.points().zip(self.values.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point<DIM>, &mut T)> {
+        let size = self.size;
+
+        self.values.iter_mut().enumerate().map(move |(idx, value)| (point_at(size, idx), value))
+    }
+
+    // The contiguous run of cells along the first axis through `point`; its first coordinate is
+    // ignored
+    pub fn row(&self, point: Point<DIM>) -> &[T] {
+        let start = self.row_start(point);
+
+        &self.values[start..(start + self.size[0])]
+    }
+
+    pub fn row_mut(&mut self, point: Point<DIM>) -> &mut [T] {
+        let start = self.row_start(point);
+
+        &mut self.values[start..(start + self.size[0])]
+    }
+
+    // All cells whose last coordinate is `index`, as a contiguous (DIM - 1)-dimensional block
+    pub fn slab(&self, index: usize) -> &[T] {
+        let slab_len = self.slab_len();
+
+        assert!(index < self.size[DIM - 1], "Slab {} out of bounds", index);
+
+        &self.values[(index * slab_len)..((index + 1) * slab_len)]
+    }
+
+    pub fn slab_mut(&mut self, index: usize) -> &mut [T] {
+        let slab_len = self.slab_len();
+
+        assert!(index < self.size[DIM - 1], "Slab {} out of bounds", index);
+
+        &mut self.values[(index * slab_len)..((index + 1) * slab_len)]
+    }
+
+    pub fn map<U, F>(&self, mut f: F) -> Grid<U, DIM> where F: FnMut(&T) -> U {
+        Grid { size: self.size, values: self.values.iter().map(&mut f).collect() }
+    }
+
+    // Resizes the grid so that its new origin lies at `origin` in the current coordinates, which
+    // may be negative to grow the grid backwards. Cells that fall outside the current grid are
+    // filled with `value`.
+    pub fn resize(&mut self, size: [usize; DIM], origin: Point<DIM>, value: T) where T: Clone {
+        let mut old_values: Vec<Option<T>> = std::mem::take(&mut self.values).into_iter().map(Some).collect();
+        let old_size = self.size;
+
+        self.values = (0..size.iter().product()).map(|idx| {
+            index_of(old_size, point_at(size, idx) + origin)
+                .and_then(|old_idx| old_values[old_idx].take())
+                .unwrap_or_else(|| value.clone())
+        }).collect();
+
+        self.size = size;
+    }
+
+    fn row_start(&self, point: Point<DIM>) -> usize {
+        let mut start = point;
+
+        start[0] = 0;
+
+        assert!(self.size[0] > 0 && self.contains(start), "Row through {} out of bounds", point);
+
+        self.index_of(start).unwrap()
+    }
+
+    fn slab_len(&self) -> usize {
+        self.size[..(DIM - 1)].iter().product()
+    }
+}
+
+impl<const DIM: usize> Grid<f64, DIM> {
+    // Samples `node` at `origin + point * spacing` for every cell
+    pub fn sample<T: NoiseNode<DIM>>(node: &T, origin: RealPoint<DIM>, spacing: f64, size: [usize; DIM]) -> Self {
+        Self::from_fn(size, |point| node.value_at(origin + point.to_real_point() * spacing))
+    }
+}
+
+fn contains<const DIM: usize>(size: [usize; DIM], point: Point<DIM>) -> bool {
+    (0..DIM).all(|axis| point[axis] >= 0 && (point[axis] as usize) < size[axis])
+}
+
+fn index_of<const DIM: usize>(size: [usize; DIM], point: Point<DIM>) -> Option<usize> {
+    if !contains(size, point) {
+        return None;
+    };
+
+    let mut idx = 0;
+
+    for axis in (0..DIM).rev() {
+        idx = idx * size[axis] + (point[axis] as usize);
+    };
+
+    Some(idx)
+}
+
+fn point_at<const DIM: usize>(size: [usize; DIM], mut idx: usize) -> Point<DIM> {
+    let mut point = Point::new([0; DIM]);
+
+    for axis in 0..DIM {
+        point[axis] = (idx % size[axis]) as i32;
+        idx /= size[axis];
+    };
+
+    point
+}
+
+impl<T, const DIM: usize> Index<Point<DIM>> for Grid<T, DIM> {
+    type Output = T;
+
+    fn index(&self, point: Point<DIM>) -> &Self::Output {
+        match self.index_of(point) {
+            Some(idx) => &self.values[idx],
+            None => panic!("Point {} out of bounds", point),
+        }
+    }
+}
+
+impl<T, const DIM: usize> IndexMut<Point<DIM>> for Grid<T, DIM> {
+    fn index_mut(&mut self, point: Point<DIM>) -> &mut Self::Output {
+        match self.index_of(point) {
+            Some(idx) => &mut self.values[idx],
+            None => panic!("Point {} out of bounds", point),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{collections::Grid, geometry::{Point, RealPoint}, noise::NoiseNode};
+
+    #[test]
+    fn indexing() {
+        let grid = Grid::from_fn([3, 2, 2], |point| point[0] + 10 * point[1] + 100 * point[2]);
+
+        assert_eq!(grid.len(), 12);
+        assert_eq!(grid[Point::new([2, 1, 1])], 112);
+        assert_eq!(grid.get(Point::new([3, 0, 0])), None);
+        assert_eq!(grid.get(Point::new([0, -1, 0])), None);
+        assert_eq!(grid.row(Point::new([1, 1, 0])), &[10, 11, 12]);
+        assert_eq!(grid.slab(1), &[100, 101, 102, 110, 111, 112]);
+
+        for (point, &value) in grid.iter() {
+            assert_eq!(grid[point], value);
+        };
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let grid = Grid::new([4, 4], 0u8);
+
+        let _ = grid[Point::new([4, 0])];
+    }
+
+    #[test]
+    fn resize() {
+        let mut grid = Grid::from_fn([3, 3], |point| point[0] + 10 * point[1]);
+
+        grid.resize([3, 2], Point::new([-1, 1]), -1);
+
+        assert_eq!(grid.size(), [3, 2]);
+        assert_eq!(grid.values(), &[-1, 10, 11, -1, 20, 21]);
+    }
+
+    #[test]
+    fn sample() {
+        struct SumNode;
+
+        impl NoiseNode<2> for SumNode {
+            fn value_at(&self, point: RealPoint<2>) -> f64 {
+                point.sum()
+            }
+        }
+
+        let grid = Grid::sample(&SumNode, RealPoint::new([1.0, 0.0]), 0.5, [2, 2]);
+
+        assert_eq!(grid.values(), &[1.0, 1.5, 1.5, 2.0]);
+    }
+}
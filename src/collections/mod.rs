@@ -1,3 +1,7 @@
 mod enum_bit_set;
+mod grid;
+mod point_queue;
 
-pub use enum_bit_set::EnumBitSet;
+pub use enum_bit_set::{byte_width, EnumBitSet, EnumIndex};
+pub use grid::Grid;
+pub(crate) use point_queue::PointQueue;
@@ -0,0 +1,66 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+use crate::geometry::Point;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct Entry<const DIM: usize> {
+    priority: f64,
+    sequence: usize,
+    point: Point<DIM>,
+}
+
+impl<const DIM: usize> Eq for Entry<DIM> {}
+
+impl<const DIM: usize> Ord for Entry<DIM> {
+    // Reversed, so that the binary heap pops the lowest priority first, and the earliest pushed
+    // among equals
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.total_cmp(&self.priority).then(other.sequence.cmp(&self.sequence))
+    }
+}
+
+impl<const DIM: usize> PartialOrd for Entry<DIM> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Min-priority queue of points with deterministic ordering among equal priorities
+pub(crate) struct PointQueue<const DIM: usize> {
+    heap: BinaryHeap<Entry<DIM>>,
+    sequence: usize,
+}
+
+impl<const DIM: usize> PointQueue<DIM> {
+    pub(crate) fn new() -> Self {
+        Self { heap: BinaryHeap::new(), sequence: 0 }
+    }
+
+    pub(crate) fn push(&mut self, point: Point<DIM>, priority: f64) {
+        self.heap.push(Entry { priority, sequence: self.sequence, point });
+        self.sequence += 1;
+    }
+
+    pub(crate) fn pop(&mut self) -> Option<(Point<DIM>, f64)> {
+        self.heap.pop().map(|entry| (entry.point, entry.priority))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{collections::PointQueue, geometry::Point};
+
+    #[test]
+    fn point_queue() {
+        let mut queue = PointQueue::new();
+
+        queue.push(Point::new([0]), f64::NAN);
+        queue.push(Point::new([1]), 1.0);
+        queue.push(Point::new([2]), 0.0);
+        queue.push(Point::new([3]), 1.0);
+
+        let order: Vec<Point<1>> = std::iter::from_fn(|| queue.pop()).map(|(point, _)| point).collect();
+
+        // Equal priorities pop in insertion order, and NaN sorts after everything else
+        assert_eq!(order, vec![Point::new([2]), Point::new([1]), Point::new([3]), Point::new([0])]);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{color::ColorBlend, utils};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ColorStop {
+    pub value: f64,
+    pub color: [u8; 3],
+}
+
+impl ColorStop {
+    pub const fn new(value: f64, color: [u8; 3]) -> Self {
+        Self { value, color }
+    }
+}
+
+// Piecewise-linear mapping from scalar values to RGB colours. Values outside of the range covered
+// by the stops take the colour of the nearest stop.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ColorRamp {
+    stops: Vec<ColorStop>,
+    blend: ColorBlend,
+}
+
+impl ColorRamp {
+    pub fn new(stops: Vec<ColorStop>) -> Self {
+        Self::with_blend(stops, ColorBlend::Srgb)
+    }
+
+    pub fn with_blend(mut stops: Vec<ColorStop>, blend: ColorBlend) -> Self {
+        assert!(!stops.is_empty(), "A colour ramp requires at least one stop");
+
+        stops.sort_by(|a, b| a.value.partial_cmp(&b.value).unwrap());
+
+        Self { stops, blend }
+    }
+
+    pub fn grayscale() -> Self {
+        Self::new(vec![
+            ColorStop::new(0.0, [0x00, 0x00, 0x00]),
+            ColorStop::new(1.0, [0xFF, 0xFF, 0xFF]),
+        ])
+    }
+
+    pub fn stops(&self) -> &[ColorStop] {
+        &self.stops
+    }
+
+    pub fn blend(&self) -> ColorBlend {
+        self.blend
+    }
+
+    // sRGB-encoded components in [0.0, 1.0]
+    pub fn rgb_at(&self, value: f64) -> [f64; 3] {
+        let upper_idx = self.stops.partition_point(|stop| stop.value <= value);
+
+        if upper_idx == 0 {
+            return unit_rgb(self.stops[0].color);
+        };
+
+        if upper_idx == self.stops.len() {
+            return unit_rgb(self.stops[upper_idx - 1].color);
+        };
+
+        let lower = self.stops[upper_idx - 1];
+        let upper = self.stops[upper_idx];
+        let bias = (value - lower.value) / (upper.value - lower.value);
+
+        let lhs = self.blend.from_srgb(unit_rgb(lower.color));
+        let rhs = self.blend.from_srgb(unit_rgb(upper.color));
+
+        let mut color = [0.0f64; 3];
+
+        for (channel, (&lhs, &rhs)) in color.iter_mut().zip(lhs.iter().zip(rhs.iter())) {
+            *channel = utils::lerp(bias, lhs, rhs);
+        };
+
+        self.blend.to_srgb(color).map(|channel| channel.clamp(0.0, 1.0))
+    }
+
+    pub fn color_at(&self, value: f64) -> [u8; 3] {
+        self.rgb_at(value).map(|channel| (channel * 255.0).round() as u8)
+    }
+
+    pub fn rgba_at(&self, value: f64) -> [u8; 4] {
+        let [r, g, b] = self.color_at(value);
+
+        [r, g, b, 0xFF]
+    }
+}
+
+fn unit_rgb(color: [u8; 3]) -> [f64; 3] {
+    color.map(|channel| (channel as f64) / 255.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::color::{ColorBlend, ColorRamp, ColorStop};
+
+    #[test]
+    fn color_at() {
+        let ramp = ColorRamp::new(vec![
+            ColorStop::new(1.0, [0xFF, 0xFF, 0xFF]),
+            ColorStop::new(0.0, [0x00, 0x00, 0x80]),
+            ColorStop::new(0.5, [0x00, 0xC0, 0x00]),
+        ]);
+
+        assert_eq!(ramp.color_at(-1.0), [0x00, 0x00, 0x80]);
+        assert_eq!(ramp.color_at(0.0), [0x00, 0x00, 0x80]);
+        assert_eq!(ramp.color_at(0.25), [0x00, 0x60, 0x40]);
+        assert_eq!(ramp.color_at(0.5), [0x00, 0xC0, 0x00]);
+        assert_eq!(ramp.color_at(0.75), [0x80, 0xE0, 0x80]);
+        assert_eq!(ramp.color_at(1.0), [0xFF, 0xFF, 0xFF]);
+        assert_eq!(ramp.color_at(2.0), [0xFF, 0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn blend_spaces() {
+        let stops = vec![
+            ColorStop::new(0.0, [0x00, 0x00, 0x00]),
+            ColorStop::new(1.0, [0xFF, 0xFF, 0xFF]),
+        ];
+
+        let srgb = ColorRamp::new(stops.clone());
+        let linear = ColorRamp::with_blend(stops.clone(), ColorBlend::LinearRgb);
+        let oklab = ColorRamp::with_blend(stops, ColorBlend::Oklab);
+
+        assert_eq!(srgb.color_at(0.5), [0x80, 0x80, 0x80]);
+        assert_eq!(linear.color_at(0.5), [0xBC, 0xBC, 0xBC]);
+        assert_eq!(oklab.color_at(0.5), [0x63, 0x63, 0x63]);
+
+        assert_eq!(oklab.color_at(0.0), [0x00, 0x00, 0x00]);
+        assert_eq!(oklab.rgba_at(1.0), [0xFF, 0xFF, 0xFF, 0xFF]);
+    }
+}
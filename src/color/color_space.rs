@@ -0,0 +1,100 @@
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ColorBlend {
+    Srgb,
+    LinearRgb,
+    Oklab,
+}
+
+impl ColorBlend {
+    // Converts sRGB-encoded components in [0.0, 1.0] into the blending space
+    pub fn from_srgb(self, color: [f64; 3]) -> [f64; 3] {
+        match self {
+            ColorBlend::Srgb => color,
+            ColorBlend::LinearRgb => srgb_to_linear(color),
+            ColorBlend::Oklab => linear_to_oklab(srgb_to_linear(color)),
+        }
+    }
+
+    pub fn to_srgb(self, color: [f64; 3]) -> [f64; 3] {
+        match self {
+            ColorBlend::Srgb => color,
+            ColorBlend::LinearRgb => linear_to_srgb(color),
+            ColorBlend::Oklab => linear_to_srgb(oklab_to_linear(color)),
+        }
+    }
+}
+
+pub fn srgb_to_linear(color: [f64; 3]) -> [f64; 3] {
+    color.map(|channel| {
+        if channel <= 0.04045 {
+            channel / 12.92
+        } else {
+            ((channel + 0.055) / 1.055).powf(2.4)
+        }
+    })
+}
+
+pub fn linear_to_srgb(color: [f64; 3]) -> [f64; 3] {
+    color.map(|channel| {
+        let channel = channel.clamp(0.0, 1.0);
+
+        if channel <= 0.0031308 {
+            channel * 12.92
+        } else {
+            channel.powf(1.0 / 2.4).mul_add(1.055, -0.055)
+        }
+    })
+}
+
+// Conversion matrices from https://bottosson.github.io/posts/oklab/
+pub fn linear_to_oklab([r, g, b]: [f64; 3]) -> [f64; 3] {
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+    [
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+    ]
+}
+
+pub fn oklab_to_linear([lightness, a, b]: [f64; 3]) -> [f64; 3] {
+    let l = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+    let m = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+    let s = (lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+    [
+         4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+        -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+        -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::color::{ColorBlend, linear_to_oklab};
+
+    #[test]
+    fn round_trip() {
+        let colors = [[0.0, 0.0, 0.0], [1.0, 1.0, 1.0], [0.2, 0.6, 0.9], [0.75, 0.1, 0.4]];
+
+        for blend in [ColorBlend::Srgb, ColorBlend::LinearRgb, ColorBlend::Oklab] {
+            for color in colors {
+                let result = blend.to_srgb(blend.from_srgb(color));
+
+                for (lhs, rhs) in result.iter().zip(color.iter()) {
+                    assert!((lhs - rhs).abs() < 1e-6, "{:?}: {:?} != {:?}", blend, result, color);
+                };
+            };
+        };
+    }
+
+    #[test]
+    fn oklab_white() {
+        let [lightness, a, b] = linear_to_oklab([1.0, 1.0, 1.0]);
+
+        assert!((lightness - 1.0).abs() < 1e-6);
+        assert!(a.abs() < 1e-6 && b.abs() < 1e-6);
+    }
+}
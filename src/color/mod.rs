@@ -0,0 +1,5 @@
+mod color_ramp;
+mod color_space;
+
+pub use color_ramp::{ColorRamp, ColorStop};
+pub use color_space::{ColorBlend, linear_to_oklab, linear_to_srgb, oklab_to_linear, srgb_to_linear};
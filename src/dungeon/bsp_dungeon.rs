@@ -0,0 +1,278 @@
+use std::ops::RangeInclusive;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use crate::{
+    collections::Grid,
+    geometry::{Aabb, Adjacency, ManhattanMetric, Point},
+    noise::NoiseNode,
+    pathfinding::PathFinder,
+    random::{ExtendedRng, Seed, Seedable},
+};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Tile {
+    Wall,
+    Floor,
+    Corridor,
+}
+
+#[derive(Copy, Clone)]
+pub enum CorridorStyle<'a> {
+    // Straight runs along one axis and then the other, with the order chosen at random
+    LShaped,
+    // Cheapest routes through a cost field of `1 + amplitude * node(point * scale)`
+    Wiggled { node: &'a dyn NoiseNode<2>, amplitude: f64, scale: f64 },
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Dungeon {
+    pub tiles: Grid<Tile, 2>,
+    pub rooms: Vec<Aabb<2>>,
+    // Pairs of indices into `rooms` joined by a corridor; together they form a spanning tree
+    pub connections: Vec<(usize, usize)>,
+}
+
+impl Dungeon {
+    pub fn connected_rooms(&self, room: usize) -> impl Iterator<Item = usize> + '_ {
+        self.connections.iter().filter_map(move |&(a, b)| {
+            if a == room {
+                Some(b)
+            } else if b == room {
+                Some(a)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+// Binary space partitioning dungeons: the area is split recursively, each leaf receives a room,
+// and sibling subtrees are joined by a corridor between their closest rooms
+#[derive(Clone)]
+pub struct BspDungeon<'a> {
+    // Areas are never split into parts narrower than this; at least three, so that every leaf
+    // fits a room inside a one-tile margin
+    pub min_leaf_size: usize,
+    pub max_depth: usize,
+    // Room extents are rolled as the sum of `room_rolls` rolls of `room_die`, like 2d4, and then
+    // clamped to fit the leaf
+    pub room_rolls: usize,
+    pub room_die: RangeInclusive<i32>,
+    pub corridor_style: CorridorStyle<'a>,
+}
+
+impl Default for BspDungeon<'_> {
+    fn default() -> Self {
+        Self {
+            min_leaf_size: 8,
+            max_depth: 5,
+            room_rolls: 2,
+            room_die: 2..=4,
+            corridor_style: CorridorStyle::LShaped,
+        }
+    }
+}
+
+impl<'a> BspDungeon<'a> {
+    pub fn generate(&self, seed: &Seed, size: [usize; 2]) -> Dungeon {
+        assert!(self.min_leaf_size >= 3, "Leaves must be at least three tiles wide");
+
+        let mut rng = ChaCha8Rng::from_seed(seed);
+        let mut dungeon = Dungeon {
+            tiles: Grid::new(size, Tile::Wall),
+            rooms: Vec::new(),
+            connections: Vec::new(),
+        };
+
+        let area = Aabb::with_size(Point::new([0, 0]), size);
+
+        if area.size().iter().all(|&length| length >= self.min_leaf_size) {
+            self.build(area, 0, &mut rng, &mut dungeon);
+        };
+
+        dungeon
+    }
+
+    // Returns the indices of the rooms placed within `area`
+    fn build(&self, area: Aabb<2>, depth: usize, rng: &mut ChaCha8Rng, dungeon: &mut Dungeon) -> Vec<usize> {
+        let min_leaf = self.min_leaf_size as i32;
+        let [width, height] = area.size().map(|length| length as i32);
+
+        let can_split = [width >= 2 * min_leaf, height >= 2 * min_leaf];
+
+        let axis = match can_split {
+            _ if depth >= self.max_depth => None,
+            [true, true] if width * 4 > height * 5 => Some(0),
+            [true, true] if height * 4 > width * 5 => Some(1),
+            [true, true] => Some(rng.gen_range(0..2)),
+            [true, false] => Some(0),
+            [false, true] => Some(1),
+            [false, false] => None,
+        };
+
+        let Some(axis) = axis else {
+            return vec![self.place_room(area, rng, dungeon)];
+        };
+
+        let length = [width, height][axis];
+        let ratio: f64 = rng.gen_range(0.4..0.6);
+        let offset: i32 = rng.round(length as f64 * ratio).unwrap();
+        let (lower, upper) = area.split(axis, area.min[axis] + offset.clamp(min_leaf, length - min_leaf));
+
+        let mut lower_rooms = self.build(lower, depth + 1, rng, dungeon);
+        let upper_rooms = self.build(upper, depth + 1, rng, dungeon);
+
+        let (a, b) = closest_pair(dungeon, &lower_rooms, &upper_rooms);
+
+        self.carve_corridor(center(dungeon.rooms[a]), center(dungeon.rooms[b]), rng, &mut dungeon.tiles);
+        dungeon.connections.push((a, b));
+
+        lower_rooms.extend(upper_rooms);
+        lower_rooms
+    }
+
+    fn place_room(&self, area: Aabb<2>, rng: &mut ChaCha8Rng, dungeon: &mut Dungeon) -> usize {
+        let interior = area.expand(-1);
+        let mut size = [0usize; 2];
+        let mut min = interior.min;
+
+        for axis in 0..2 {
+            let available = interior.size()[axis] as i32;
+            let rolled: i32 = rng.multi_gen_range(self.room_rolls, self.room_die.clone());
+            let length = rolled.clamp(available.min(3), available);
+
+            min[axis] += rng.gen_range(0..=(available - length));
+            size[axis] = length as usize;
+        };
+
+        let room = Aabb::with_size(min, size);
+
+        for point in room.points() {
+            dungeon.tiles[point] = Tile::Floor;
+        };
+
+        dungeon.rooms.push(room);
+        dungeon.rooms.len() - 1
+    }
+
+    fn carve_corridor(
+        &self,
+        start: Point<2>,
+        end: Point<2>,
+        rng: &mut ChaCha8Rng,
+        tiles: &mut Grid<Tile, 2>,
+    ) {
+        let path: Vec<Point<2>> = match self.corridor_style {
+            CorridorStyle::LShaped => {
+                let corner = if rng.gen_bool(0.5) {
+                    Point::new([end[0], start[1]])
+                } else {
+                    Point::new([start[0], end[1]])
+                };
+
+                start.line_to(corner).chain(corner.line_to(end)).collect()
+            },
+
+            CorridorStyle::Wiggled { node, amplitude, scale } => {
+                // Corridors stay off the outer edge, so the dungeon remains enclosed
+                let interior = tiles.bounds().expand(-1);
+
+                let cost = |_: Point<2>, to: Point<2>| {
+                    if interior.contains(to) {
+                        Some(1.0 + amplitude * node.value_at(to.to_real_point() * scale).max(0.0))
+                    } else {
+                        None
+                    }
+                };
+
+                PathFinder::<2, ManhattanMetric, _, _>::new(Adjacency::VonNeumann, cost)
+                    .find_path(start, end)
+                    .map_or_else(Vec::new, |path| path.points)
+            },
+        };
+
+        for point in path {
+            if tiles[point] == Tile::Wall {
+                tiles[point] = Tile::Corridor;
+            };
+        };
+    }
+}
+
+fn center(room: Aabb<2>) -> Point<2> {
+    Point::new([(room.min[0] + room.max[0] - 1) / 2, (room.min[1] + room.max[1] - 1) / 2])
+}
+
+// The rooms, one from each side, with the nearest centres
+fn closest_pair(dungeon: &Dungeon, lhs: &[usize], rhs: &[usize]) -> (usize, usize) {
+    let mut best = (lhs[0], rhs[0], i32::MAX);
+
+    for &a in lhs {
+        for &b in rhs {
+            let distance = (center(dungeon.rooms[a]) - center(dungeon.rooms[b])).abs().sum();
+
+            if distance < best.2 {
+                best = (a, b, distance);
+            };
+        };
+    };
+
+    (best.0, best.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        dungeon::{BspDungeon, CorridorStyle, Tile},
+        geometry::Adjacency,
+        noise::PerlinNode,
+        random::{Seed, Wyhash},
+        regions::RegionLabels,
+    };
+
+    #[test]
+    fn bsp_dungeon() {
+        let seed = Seed::DEFAULT_SEED;
+        let generator = BspDungeon::default();
+        let dungeon = generator.generate(&seed, [80, 50]);
+
+        assert_eq!(dungeon, generator.generate(&seed, [80, 50]));
+        assert!(dungeon.rooms.len() >= 8);
+        assert_eq!(dungeon.connections.len(), dungeon.rooms.len() - 1);
+
+        for (idx, room) in dungeon.rooms.iter().enumerate() {
+            assert!(dungeon.connected_rooms(idx).count() > 0);
+
+            for other in &dungeon.rooms[(idx + 1)..] {
+                assert_eq!(room.intersection(other), None);
+            };
+        };
+
+        // Every room and corridor tile is reachable from every other
+        let labels = RegionLabels::new(&dungeon.tiles, Adjacency::VonNeumann, |&tile| tile != Tile::Wall);
+
+        assert_eq!(labels.regions().len(), 1);
+    }
+
+    #[test]
+    fn wiggled_corridors() {
+        let seed = Seed::DEFAULT_SEED;
+        let perlin: PerlinNode<2, Wyhash> = PerlinNode::new(&seed);
+
+        let generator = BspDungeon {
+            corridor_style: CorridorStyle::Wiggled { node: &perlin, amplitude: 8.0, scale: 0.15 },
+            ..BspDungeon::default()
+        };
+
+        let dungeon = generator.generate(&seed, [64, 64]);
+        let labels = RegionLabels::new(&dungeon.tiles, Adjacency::VonNeumann, |&tile| tile != Tile::Wall);
+
+        assert_eq!(labels.regions().len(), 1);
+
+        let border = dungeon.tiles.bounds().points().filter(|point| {
+            point[0] == 0 || point[1] == 0 || point[0] == 63 || point[1] == 63
+        });
+
+        assert!(border.into_iter().all(|point| dungeon.tiles[point] == Tile::Wall));
+    }
+}
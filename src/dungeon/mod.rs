@@ -0,0 +1,3 @@
+mod bsp_dungeon;
+
+pub use bsp_dungeon::{BspDungeon, CorridorStyle, Dungeon, Tile};
@@ -0,0 +1,179 @@
+mod permissive;
+mod recursive_shadowcasting;
+mod symmetric_shadowcasting;
+
+use crate::{collections::Grid, geometry::{DistanceMetric, Point}};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum FovAlgorithm {
+    // Björn Bergström's octant-based shadowcasting; fast, but not symmetric
+    RecursiveShadowcasting,
+    // Albert Ford's variant, in which floor cells see each other either both ways or neither
+    SymmetricShadowcasting,
+    // Jonathon Duerig's precise permissive FOV, in which cells are visible if any unobstructed
+    // segment joins the origin cell to them; also symmetric
+    Permissive,
+}
+
+// Reports every cell visible from `origin` exactly once, including the origin itself. Cells are
+// only considered within `radius` of the origin under the metric. Opaque cells are visible but
+// block sight beyond them.
+pub fn compute_fov<T, F, V>(
+    algorithm: FovAlgorithm,
+    origin: Point<2>,
+    radius: f64,
+    is_opaque: F,
+    mut visit: V,
+) where T: DistanceMetric, F: Fn(Point<2>) -> bool, V: FnMut(Point<2>) {
+    let reach = radius.max(0.0).floor() as i32;
+    let corner = origin - reach;
+    let side = (2 * reach + 1) as usize;
+
+    let mut seen = Grid::new([side, side], false);
+
+    let mut mark = |cell: Point<2>| {
+        if (cell - origin).to_real_point().magnitude::<T>() > radius {
+            return;
+        };
+
+        if let Some(is_seen) = seen.get_mut(cell - corner) {
+            if !*is_seen {
+                *is_seen = true;
+                visit(cell);
+            };
+        };
+    };
+
+    let compute = match algorithm {
+        FovAlgorithm::RecursiveShadowcasting => recursive_shadowcasting::compute,
+        FovAlgorithm::SymmetricShadowcasting => symmetric_shadowcasting::compute,
+        FovAlgorithm::Permissive => permissive::compute,
+    };
+
+    compute(origin, reach, &is_opaque, &mut mark);
+}
+
+// Sets the visible cells that lie inside `visible`, leaving all others untouched
+pub fn compute_fov_grid<T, F>(
+    algorithm: FovAlgorithm,
+    origin: Point<2>,
+    radius: f64,
+    is_opaque: F,
+    visible: &mut Grid<bool, 2>,
+) where T: DistanceMetric, F: Fn(Point<2>) -> bool {
+    compute_fov::<T, _, _>(algorithm, origin, radius, is_opaque, |cell| {
+        if let Some(is_visible) = visible.get_mut(cell) {
+            *is_visible = true;
+        };
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        collections::Grid,
+        fov::{compute_fov, compute_fov_grid, FovAlgorithm},
+        geometry::{ChebyshevMetric, EuclideanMetric, Point},
+    };
+
+    const ALGORITHMS: [FovAlgorithm; 3] = [
+        FovAlgorithm::RecursiveShadowcasting,
+        FovAlgorithm::SymmetricShadowcasting,
+        FovAlgorithm::Permissive,
+    ];
+
+    // A pillar east of the origin, and a wall along x = -3
+    fn is_opaque(cell: Point<2>) -> bool {
+        cell == Point::new([2, 0]) || cell[0] == -3
+    }
+
+    #[test]
+    fn open_room() {
+        for algorithm in ALGORITHMS {
+            let mut cells = Vec::new();
+
+            compute_fov::<ChebyshevMetric, _, _>(algorithm, Point::new([0, 0]), 3.0, |_| false, |cell| {
+                cells.push(cell);
+            });
+
+            assert_eq!(cells.len(), 49, "{:?}", algorithm);
+
+            let mut circle = 0;
+
+            compute_fov::<EuclideanMetric, _, _>(algorithm, Point::new([0, 0]), 3.0, |_| false, |_| {
+                circle += 1;
+            });
+
+            assert_eq!(circle, 29, "{:?}", algorithm);
+        };
+    }
+
+    #[test]
+    fn shadows() {
+        for algorithm in ALGORITHMS {
+            let mut visible = Grid::new([16, 16], false);
+            let origin = Point::new([8, 8]);
+            let offset = origin;
+
+            let is_opaque = |cell| is_opaque(cell - offset);
+
+            compute_fov_grid::<ChebyshevMetric, _>(algorithm, origin, 6.0, is_opaque, &mut visible);
+
+            let is_visible = |x: i32, y: i32| visible[offset + Point::new([x, y])];
+
+            assert!(is_visible(0, 0), "{:?}", algorithm);
+            assert!(is_visible(2, 0), "{:?}", algorithm);
+            assert!(!is_visible(3, 0), "{:?}", algorithm);
+            assert!(!is_visible(5, 0), "{:?}", algorithm);
+            assert!(is_visible(5, 3), "{:?}", algorithm);
+            assert!(is_visible(-3, 1), "{:?}", algorithm);
+            assert!(!is_visible(-4, 0), "{:?}", algorithm);
+        };
+    }
+
+    #[test]
+    fn symmetric() {
+        let is_opaque = |cell: Point<2>| {
+            (cell[0] * 7 + cell[1] * 13).rem_euclid(5) == 0 && cell != Point::new([0, 0])
+        };
+
+        for algorithm in [FovAlgorithm::SymmetricShadowcasting, FovAlgorithm::Permissive] {
+            let sees = |from: Point<2>, to: Point<2>| {
+                let mut found = false;
+
+                compute_fov::<EuclideanMetric, _, _>(algorithm, from, 8.0, is_opaque, |cell| {
+                    found |= cell == to;
+                });
+
+                found
+            };
+
+            let origin = Point::new([0, 0]);
+
+            for target in origin.within::<EuclideanMetric>(8.0).filter(|&cell| !is_opaque(cell)) {
+                assert_eq!(sees(origin, target), sees(target, origin), "{:?}: {}", algorithm, target);
+            };
+        };
+    }
+
+    #[test]
+    fn permissive_corners() {
+        // Pillars either side of the diagonal leave a gap only as wide as their shared corner
+        let is_opaque = |cell: Point<2>| cell == Point::new([1, 0]) || cell == Point::new([0, 1]);
+        let mut visible = Grid::new([8, 8], false);
+
+        let algorithm = FovAlgorithm::Permissive;
+
+        compute_fov_grid::<ChebyshevMetric, _>(algorithm, Point::new([0, 0]), 7.0, is_opaque, &mut visible);
+
+        // Segments through the corner reach every cell off the axes, but those merely grazing the
+        // pillars' edges do not count
+        assert!(visible[Point::new([1, 1])]);
+        assert!(visible[Point::new([2, 1])]);
+        assert!(visible[Point::new([7, 1])]);
+        assert!(visible[Point::new([6, 7])]);
+        assert!(!visible[Point::new([2, 0])]);
+        assert!(!visible[Point::new([0, 2])]);
+        assert!(!visible[Point::new([7, 0])]);
+    }
+}
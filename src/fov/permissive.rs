@@ -0,0 +1,219 @@
+use crate::geometry::Point;
+
+// Line between two cell corners in quadrant-local coordinates, where the origin cell spans the unit
+// square at (0, 0)
+#[derive(Copy, Clone, Debug)]
+struct Line {
+    near: Point<2>,
+    far: Point<2>,
+}
+
+impl Line {
+    // Positive when the line passes below the point, negative when above it and zero through it
+    fn relative_slope(self, point: Point<2>) -> i32 {
+        let delta = self.far - self.near;
+
+        delta[1] * (self.far[0] - point[0]) - delta[0] * (self.far[1] - point[1])
+    }
+
+    fn is_below(self, point: Point<2>) -> bool {
+        self.relative_slope(point) > 0
+    }
+
+    fn is_below_or_collinear(self, point: Point<2>) -> bool {
+        self.relative_slope(point) >= 0
+    }
+
+    fn is_above(self, point: Point<2>) -> bool {
+        self.relative_slope(point) < 0
+    }
+
+    fn is_above_or_collinear(self, point: Point<2>) -> bool {
+        self.relative_slope(point) <= 0
+    }
+
+    fn is_collinear(self, point: Point<2>) -> bool {
+        self.relative_slope(point) == 0
+    }
+}
+
+// Corner of an opaque cell which a view's line has been bent around, chained to earlier bumps on
+// the same side of the view
+#[derive(Copy, Clone, Debug)]
+struct Bump {
+    point: Point<2>,
+    parent: Option<usize>,
+}
+
+// Wedge of unobstructed sight lines between a shallow and a steep bounding line
+#[derive(Copy, Clone, Debug)]
+struct View {
+    shallow: Line,
+    steep: Line,
+    shallow_bump: Option<usize>,
+    steep_bump: Option<usize>,
+}
+
+struct Quadrant {
+    views: Vec<View>,
+    bumps: Vec<Bump>,
+}
+
+impl Quadrant {
+    fn new(reach: i32) -> Self {
+        let view = View {
+            shallow: Line { near: Point::new([0, 1]), far: Point::new([reach, 0]) },
+            steep: Line { near: Point::new([1, 0]), far: Point::new([0, reach]) },
+            shallow_bump: None,
+            steep_bump: None,
+        };
+
+        Self { views: vec![view], bumps: Vec::new() }
+    }
+
+    // Visits the cell at `offset` if it lies within a view, narrowing or splitting that view if it
+    // is opaque. `current` is the first view which may contain the cell; views are ordered from
+    // shallow to steep, as are the cells along each diagonal.
+    fn visit_square<F, V>(
+        &mut self,
+        offset: Point<2>,
+        cell: Point<2>,
+        current: &mut usize,
+        is_opaque: &F,
+        visit: &mut V,
+    ) where F: Fn(Point<2>) -> bool, V: FnMut(Point<2>) {
+        let top_left = offset + Point::new([0, 1]);
+        let bottom_right = offset + Point::new([1, 0]);
+
+        while *current < self.views.len() && self.views[*current].steep.is_below_or_collinear(bottom_right) {
+            *current += 1;
+        };
+
+        if *current == self.views.len() || self.views[*current].shallow.is_above_or_collinear(top_left) {
+            return;
+        };
+
+        visit(cell);
+
+        if !is_opaque(cell) {
+            return;
+        };
+
+        let view = self.views[*current];
+
+        match (view.shallow.is_above(bottom_right), view.steep.is_below(top_left)) {
+            // The cell covers the whole view
+            (true, true) => {
+                self.views.remove(*current);
+            },
+            (true, false) => {
+                self.add_shallow_bump(*current, top_left);
+                self.check_view(*current);
+            },
+            (false, true) => {
+                self.add_steep_bump(*current, bottom_right);
+                self.check_view(*current);
+            },
+            // The cell lies strictly inside the view, splitting it in two
+            (false, false) => {
+                self.views.insert(*current, view);
+                self.add_steep_bump(*current, bottom_right);
+
+                let steep = if self.check_view(*current) { *current + 1 } else { *current };
+
+                self.add_shallow_bump(steep, top_left);
+                self.check_view(steep);
+            },
+        };
+    }
+
+    // Raises the shallow line to pass above `point`, pivoting it around any steep bumps it would
+    // otherwise cross
+    fn add_shallow_bump(&mut self, index: usize, point: Point<2>) {
+        let view = &mut self.views[index];
+
+        view.shallow.far = point;
+        self.bumps.push(Bump { point, parent: view.shallow_bump });
+        view.shallow_bump = Some(self.bumps.len() - 1);
+
+        let mut bump = view.steep_bump;
+
+        while let Some(idx) = bump {
+            if view.shallow.is_above(self.bumps[idx].point) {
+                view.shallow.near = self.bumps[idx].point;
+            };
+
+            bump = self.bumps[idx].parent;
+        };
+    }
+
+    // Lowers the steep line to pass below `point`, pivoting it around any shallow bumps it would
+    // otherwise cross
+    fn add_steep_bump(&mut self, index: usize, point: Point<2>) {
+        let view = &mut self.views[index];
+
+        view.steep.far = point;
+        self.bumps.push(Bump { point, parent: view.steep_bump });
+        view.steep_bump = Some(self.bumps.len() - 1);
+
+        let mut bump = view.shallow_bump;
+
+        while let Some(idx) = bump {
+            if view.steep.is_below(self.bumps[idx].point) {
+                view.steep.near = self.bumps[idx].point;
+            };
+
+            bump = self.bumps[idx].parent;
+        };
+    }
+
+    // Removes the view if it has collapsed onto a single line leaving the origin cell through one of
+    // its outer corners, returning whether it survived
+    fn check_view(&mut self, index: usize) -> bool {
+        let View { shallow, steep, .. } = self.views[index];
+
+        let is_closed = shallow.is_collinear(steep.near)
+            && shallow.is_collinear(steep.far)
+            && (shallow.is_collinear(Point::new([0, 1])) || shallow.is_collinear(Point::new([1, 0])));
+
+        if is_closed {
+            self.views.remove(index);
+        };
+
+        !is_closed
+    }
+}
+
+// Signs mapping quadrant-local offsets onto the grid
+const QUADRANTS: [[i32; 2]; 4] = [[1, 1], [-1, 1], [-1, -1], [1, -1]];
+
+// Precise permissive FOV (Jonathon Duerig): a cell is visible if some segment from anywhere in the
+// origin cell to anywhere in it passes through the interior of no opaque cell in between. Each
+// quadrant is swept one diagonal at a time, tracking the views which remain open.
+pub(crate) fn compute<F, V>(origin: Point<2>, reach: i32, is_opaque: &F, visit: &mut V)
+where F: Fn(Point<2>) -> bool, V: FnMut(Point<2>) {
+    visit(origin);
+
+    for [sign_x, sign_y] in QUADRANTS {
+        let mut quadrant = Quadrant::new(reach);
+
+        for diagonal in 1..=(2 * reach) {
+            let mut current = 0;
+
+            for y in (diagonal - reach).max(0)..=diagonal.min(reach) {
+                if current == quadrant.views.len() {
+                    break;
+                };
+
+                let offset = Point::new([diagonal - y, y]);
+                let cell = origin + Point::new([offset[0] * sign_x, offset[1] * sign_y]);
+
+                quadrant.visit_square(offset, cell, &mut current, is_opaque, visit);
+            };
+
+            if quadrant.views.is_empty() {
+                break;
+            };
+        };
+    };
+}
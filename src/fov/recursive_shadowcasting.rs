@@ -0,0 +1,80 @@
+use crate::geometry::Point;
+
+// Maps octant-local (column, row) offsets onto the grid
+const OCTANTS: [[i32; 4]; 8] = [
+    [1, 0, 0, 1],
+    [0, 1, 1, 0],
+    [0, -1, 1, 0],
+    [-1, 0, 0, 1],
+    [-1, 0, 0, -1],
+    [0, -1, -1, 0],
+    [0, 1, -1, 0],
+    [1, 0, 0, -1],
+];
+
+pub(crate) fn compute<F, V>(origin: Point<2>, reach: i32, is_opaque: &F, visit: &mut V)
+where F: Fn(Point<2>) -> bool, V: FnMut(Point<2>) {
+    visit(origin);
+
+    for transform in OCTANTS {
+        cast_light(origin, reach, 1, 1.0, 0.0, transform, is_opaque, visit);
+    };
+}
+
+#[allow(clippy::too_many_arguments)]
+fn cast_light<F, V>(
+    origin: Point<2>,
+    reach: i32,
+    first_row: i32,
+    mut start_slope: f64,
+    end_slope: f64,
+    [xx, xy, yx, yy]: [i32; 4],
+    is_opaque: &F,
+    visit: &mut V,
+) where F: Fn(Point<2>) -> bool, V: FnMut(Point<2>) {
+    if start_slope < end_slope {
+        return;
+    };
+
+    let mut next_start_slope = start_slope;
+
+    for row in first_row..=reach {
+        let mut is_blocked = false;
+
+        for dx in -row..=0 {
+            let dy = -row;
+
+            let left_slope = (dx as f64 - 0.5) / (dy as f64 + 0.5);
+            let right_slope = (dx as f64 + 0.5) / (dy as f64 - 0.5);
+
+            if start_slope < right_slope {
+                continue;
+            } else if end_slope > left_slope {
+                break;
+            };
+
+            let cell = origin + Point::new([dx * xx + dy * xy, dx * yx + dy * yy]);
+
+            visit(cell);
+
+            if is_blocked {
+                if is_opaque(cell) {
+                    next_start_slope = right_slope;
+                } else {
+                    is_blocked = false;
+                    start_slope = next_start_slope;
+                };
+            } else if is_opaque(cell) && row < reach {
+                is_blocked = true;
+                let transform = [xx, xy, yx, yy];
+
+                cast_light(origin, reach, row + 1, start_slope, left_slope, transform, is_opaque, visit);
+                next_start_slope = right_slope;
+            };
+        };
+
+        if is_blocked {
+            break;
+        };
+    };
+}
@@ -0,0 +1,114 @@
+use crate::geometry::Point;
+
+// Exact slope `numerator / denominator`, with a positive denominator
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+struct Slope {
+    numerator: i32,
+    denominator: i32,
+}
+
+impl Slope {
+    const fn new(numerator: i32, denominator: i32) -> Self {
+        Self { numerator, denominator }
+    }
+
+    // Slope through the near edge of the cell at (depth, column)
+    fn of_cell(depth: i32, column: i32) -> Self {
+        Self::new(2 * column - 1, 2 * depth)
+    }
+
+    // `depth * slope`, rounding halves up
+    fn round_ties_up(self, depth: i32) -> i32 {
+        (2 * depth * self.numerator + self.denominator).div_euclid(2 * self.denominator)
+    }
+
+    // `depth * slope`, rounding halves down
+    fn round_ties_down(self, depth: i32) -> i32 {
+        -(self.denominator - 2 * depth * self.numerator).div_euclid(2 * self.denominator)
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Row {
+    depth: i32,
+    start_slope: Slope,
+    end_slope: Slope,
+}
+
+impl Row {
+    fn next(self) -> Self {
+        Self { depth: self.depth + 1, ..self }
+    }
+
+    fn columns(self) -> std::ops::RangeInclusive<i32> {
+        self.start_slope.round_ties_up(self.depth)..=self.end_slope.round_ties_down(self.depth)
+    }
+
+    // Whether the cell's centre lies within the row's slopes
+    fn is_symmetric(self, column: i32) -> bool {
+        let start = self.start_slope;
+        let end = self.end_slope;
+
+        column * start.denominator >= self.depth * start.numerator
+            && column * end.denominator <= self.depth * end.numerator
+    }
+}
+
+// Maps quadrant-local (depth, column) offsets onto the grid: north, east, south and west
+const QUADRANTS: [[i32; 4]; 4] = [
+    [0, 1, -1, 0],
+    [1, 0, 0, 1],
+    [0, 1, 1, 0],
+    [-1, 0, 0, 1],
+];
+
+pub(crate) fn compute<F, V>(origin: Point<2>, reach: i32, is_opaque: &F, visit: &mut V)
+where F: Fn(Point<2>) -> bool, V: FnMut(Point<2>) {
+    visit(origin);
+
+    for transform in QUADRANTS {
+        let first_row = Row { depth: 1, start_slope: Slope::new(-1, 1), end_slope: Slope::new(1, 1) };
+
+        scan(origin, reach, first_row, transform, is_opaque, visit);
+    };
+}
+
+fn scan<F, V>(origin: Point<2>, reach: i32, mut row: Row, transform: [i32; 4], is_opaque: &F, visit: &mut V)
+where F: Fn(Point<2>) -> bool, V: FnMut(Point<2>) {
+    if row.depth > reach {
+        return;
+    };
+
+    let [dx_depth, dx_column, dy_depth, dy_column] = transform;
+    let to_cell = |depth: i32, column: i32| {
+        origin + Point::new([depth * dx_depth + column * dx_column, depth * dy_depth + column * dy_column])
+    };
+
+    let mut was_opaque = None;
+
+    for column in row.columns() {
+        let cell = to_cell(row.depth, column);
+        let opaque = is_opaque(cell);
+
+        if opaque || row.is_symmetric(column) {
+            visit(cell);
+        };
+
+        if was_opaque == Some(true) && !opaque {
+            row.start_slope = Slope::of_cell(row.depth, column);
+        };
+
+        if was_opaque == Some(false) && opaque {
+            let mut next_row = row.next();
+
+            next_row.end_slope = Slope::of_cell(row.depth, column);
+            scan(origin, reach, next_row, transform, is_opaque, visit);
+        };
+
+        was_opaque = Some(opaque);
+    };
+
+    if was_opaque == Some(false) {
+        scan(origin, reach, row.next(), transform, is_opaque, visit);
+    };
+}
@@ -0,0 +1,245 @@
+use std::cmp::Ordering;
+use crate::geometry::{Point, RealAabb};
+
+// Half-open box of lattice points, from `min` inclusive to `max` exclusive along every axis. The
+// box is empty if `max` does not exceed `min` along some axis.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Aabb<const DIM: usize> {
+    pub min: Point<DIM>,
+    pub max: Point<DIM>,
+}
+
+impl<const DIM: usize> Aabb<DIM> {
+    pub const fn new(min: Point<DIM>, max: Point<DIM>) -> Self {
+        Self { min, max }
+    }
+
+    pub fn with_size(min: Point<DIM>, size: [usize; DIM]) -> Self {
+        Self { min, max: min + Point::new(size.map(|length| length as i32)) }
+    }
+
+    pub fn size(&self) -> [usize; DIM] {
+        let mut size = [0; DIM];
+
+        for (axis, length) in size.iter_mut().enumerate() {
+            *length = (self.max[axis] - self.min[axis]).max(0) as usize;
+        };
+
+        size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..DIM).any(|axis| self.max[axis] <= self.min[axis])
+    }
+
+    // Number of lattice points inside
+    pub fn volume(&self) -> usize {
+        self.size().iter().product()
+    }
+
+    pub fn contains(&self, point: Point<DIM>) -> bool {
+        (0..DIM).all(|axis| point[axis] >= self.min[axis] && point[axis] < self.max[axis])
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut intersection = *self;
+
+        for axis in 0..DIM {
+            intersection.min[axis] = self.min[axis].max(other.min[axis]);
+            intersection.max[axis] = self.max[axis].min(other.max[axis]);
+        };
+
+        if intersection.is_empty() { None } else { Some(intersection) }
+    }
+
+    // Smallest box containing both; empty boxes are ignored
+    pub fn union(&self, other: &Self) -> Self {
+        if self.is_empty() {
+            return *other;
+        };
+
+        if other.is_empty() {
+            return *self;
+        };
+
+        let mut union = *self;
+
+        for axis in 0..DIM {
+            union.min[axis] = self.min[axis].min(other.min[axis]);
+            union.max[axis] = self.max[axis].max(other.max[axis]);
+        };
+
+        union
+    }
+
+    // Grows the box by `amount` on every side; negative amounts shrink it
+    pub fn expand(&self, amount: i32) -> Self {
+        Self { min: self.min - amount, max: self.max + amount }
+    }
+
+    // Nearest point inside a non-empty box
+    pub fn clamp(&self, point: Point<DIM>) -> Point<DIM> {
+        assert!(!self.is_empty(), "Cannot clamp to an empty box");
+
+        let mut clamped = point;
+
+        for axis in 0..DIM {
+            clamped[axis] = point[axis].clamp(self.min[axis], self.max[axis] - 1);
+        };
+
+        clamped
+    }
+
+    // Splits the box at coordinate `at` along `axis`; the first half holds the points below it
+    pub fn split(&self, axis: usize, at: i32) -> (Self, Self) {
+        let at = at.clamp(self.min[axis], self.max[axis].max(self.min[axis]));
+        let (mut lower, mut upper) = (*self, *self);
+
+        lower.max[axis] = at;
+        upper.min[axis] = at;
+
+        (lower, upper)
+    }
+
+    // Tiles the box with chunks of `chunk_size`, in row-major order; chunks along the far edges are
+    // truncated to fit
+    pub fn subdivide(&self, chunk_size: [usize; DIM]) -> Vec<Self> {
+        assert!(chunk_size.iter().all(|&length| length > 0), "Chunk size must be non-zero");
+
+        let mut counts = [0usize; DIM];
+
+        for (axis, count) in counts.iter_mut().enumerate() {
+            *count = self.size()[axis].div_ceil(chunk_size[axis]);
+        };
+
+        Aabb::with_size(Point::new([0; DIM]), counts).points().map(|chunk| {
+            let mut min = self.min;
+
+            for axis in 0..DIM {
+                min[axis] += chunk[axis] * (chunk_size[axis] as i32);
+            };
+
+            Aabb::with_size(min, chunk_size).intersection(self).unwrap()
+        }).collect()
+    }
+
+    pub fn to_real_aabb(&self) -> RealAabb<DIM> {
+        RealAabb::new(self.min.to_real_point(), self.max.to_real_point())
+    }
+
+    // Every point inside, in row-major order: the first axis varies fastest, as in `Grid`
+    pub fn points(&self) -> AabbPoints<DIM> {
+        AabbPoints { aabb: *self, next: if self.is_empty() { None } else { Some(self.min) } }
+    }
+
+    // Every point inside, in Morton (Z-curve) order relative to `min`, with the first axis in the
+    // least significant position. Boxes need not be powers of two in size.
+    pub fn morton_points(&self) -> impl Iterator<Item = Point<DIM>> {
+        let mut points: Vec<Point<DIM>> = self.points().collect();
+        let min = self.min;
+
+        points.sort_by(|&a, &b| morton_cmp(a - min, b - min));
+        points.into_iter()
+    }
+}
+
+// Compares along the axis with the most significant differing bit, without interleaving
+fn morton_cmp<const DIM: usize>(a: Point<DIM>, b: Point<DIM>) -> Ordering {
+    let less_msb = |x: u32, y: u32| x < y && x < (x ^ y);
+
+    let mut major_axis = DIM - 1;
+    let mut major_bits = 0u32;
+
+    for axis in (0..DIM).rev() {
+        let bits = (a[axis] ^ b[axis]) as u32;
+
+        if less_msb(major_bits, bits) {
+            major_axis = axis;
+            major_bits = bits;
+        };
+    };
+
+    a[major_axis].cmp(&b[major_axis])
+}
+
+pub struct AabbPoints<const DIM: usize> {
+    aabb: Aabb<DIM>,
+    next: Option<Point<DIM>>,
+}
+
+impl<const DIM: usize> Iterator for AabbPoints<DIM> {
+    type Item = Point<DIM>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        let mut next = current;
+
+        self.next = None;
+
+        for axis in 0..DIM {
+            next[axis] += 1;
+
+            if next[axis] < self.aabb.max[axis] {
+                self.next = Some(next);
+                break;
+            };
+
+            next[axis] = self.aabb.min[axis];
+        };
+
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Aabb, Point};
+
+    #[test]
+    fn set_operations() {
+        let a = Aabb::new(Point::new([0, 0]), Point::new([4, 3]));
+        let b = Aabb::new(Point::new([2, 1]), Point::new([6, 6]));
+
+        assert_eq!(a.volume(), 12);
+        assert!(a.contains(Point::new([3, 2])));
+        assert!(!a.contains(Point::new([4, 2])));
+        assert_eq!(a.intersection(&b), Some(Aabb::new(Point::new([2, 1]), Point::new([4, 3]))));
+        assert_eq!(a.union(&b), Aabb::new(Point::new([0, 0]), Point::new([6, 6])));
+        assert_eq!(a.intersection(&Aabb::new(Point::new([4, 0]), Point::new([5, 1]))), None);
+        assert_eq!(a.expand(1), Aabb::new(Point::new([-1, -1]), Point::new([5, 4])));
+        assert_eq!(a.clamp(Point::new([10, -3])), Point::new([3, 0]));
+    }
+
+    #[test]
+    fn points() {
+        let aabb = Aabb::new(Point::new([1, 1]), Point::new([3, 3]));
+
+        assert_eq!(aabb.points().collect::<Vec<_>>(), vec![
+            Point::new([1, 1]), Point::new([2, 1]), Point::new([1, 2]), Point::new([2, 2]),
+        ]);
+
+        let morton: Vec<Point<2>> = Aabb::with_size(Point::new([0, 0]), [4, 4]).morton_points().collect();
+
+        assert_eq!(&morton[..8], &[
+            Point::new([0, 0]), Point::new([1, 0]), Point::new([0, 1]), Point::new([1, 1]),
+            Point::new([2, 0]), Point::new([3, 0]), Point::new([2, 1]), Point::new([3, 1]),
+        ]);
+
+        assert_eq!(Aabb::new(Point::new([0, 0, 0]), Point::new([2, 0, 2])).points().count(), 0);
+    }
+
+    #[test]
+    fn split() {
+        let aabb = Aabb::with_size(Point::new([0, 0]), [5, 3]);
+        let (lower, upper) = aabb.split(0, 2);
+
+        assert_eq!(lower.size(), [2, 3]);
+        assert_eq!(upper.size(), [3, 3]);
+
+        let chunks = aabb.subdivide([2, 2]);
+
+        assert_eq!(chunks.len(), 6);
+        assert_eq!(chunks[5], Aabb::new(Point::new([4, 2]), Point::new([5, 3])));
+        assert_eq!(chunks.iter().map(|chunk| chunk.volume()).sum::<usize>(), aabb.volume());
+    }
+}
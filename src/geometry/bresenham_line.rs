@@ -0,0 +1,95 @@
+use crate::geometry::Point;
+
+// Lattice points from `start` to `end` inclusive, taking exactly one step along the axis of
+// greatest change each iteration, so consecutive points are Moore neighbours
+pub struct BresenhamLine<const DIM: usize> {
+    current: Point<DIM>,
+    signs: Point<DIM>,
+    deltas: Point<DIM>,
+    errors: Point<DIM>,
+    num_steps: i32,
+    remaining: i32,
+}
+
+impl<const DIM: usize> BresenhamLine<DIM> {
+    pub fn new(start: Point<DIM>, end: Point<DIM>) -> Self {
+        let difference = end - start;
+        let deltas = difference.abs();
+        let num_steps = deltas.iter().copied().max().unwrap_or(0);
+
+        let mut signs = Point::new([0; DIM]);
+
+        for axis in 0..DIM {
+            signs[axis] = difference[axis].signum();
+        };
+
+        Self {
+            current: start,
+            signs,
+            deltas,
+            errors: Point::new([num_steps / 2; DIM]),
+            num_steps,
+            remaining: num_steps + 1,
+        }
+    }
+}
+
+impl<const DIM: usize> Iterator for BresenhamLine<DIM> {
+    type Item = Point<DIM>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        };
+
+        let point = self.current;
+
+        self.remaining -= 1;
+
+        for axis in 0..DIM {
+            self.errors[axis] -= self.deltas[axis];
+
+            if self.errors[axis] < 0 {
+                self.errors[axis] += self.num_steps;
+                self.current[axis] += self.signs[axis];
+            };
+        };
+
+        Some(point)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining as usize, Some(self.remaining as usize))
+    }
+}
+
+impl<const DIM: usize> ExactSizeIterator for BresenhamLine<DIM> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::Point;
+
+    #[test]
+    fn bresenham_line() {
+        let line: Vec<Point<2>> = Point::new([0, 0]).line_to(Point::new([4, 2])).collect();
+
+        assert_eq!(line, vec![
+            Point::new([0, 0]),
+            Point::new([1, 0]),
+            Point::new([2, 1]),
+            Point::new([3, 1]),
+            Point::new([4, 2]),
+        ]);
+
+        let start = Point::new([3, -2, 7]);
+        let end = Point::new([-4, 5, 1]);
+        let line: Vec<Point<3>> = start.line_to(end).collect();
+
+        assert_eq!(line.len(), 8);
+        assert_eq!(line.first(), Some(&start));
+        assert_eq!(line.last(), Some(&end));
+        assert!(line.windows(2).all(|pair| (pair[1] - pair[0]).abs().iter().all(|&step| step <= 1)));
+
+        assert_eq!(start.line_to(start).collect::<Vec<_>>(), vec![start]);
+    }
+}
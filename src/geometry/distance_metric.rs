@@ -31,6 +31,24 @@ impl DistanceMetric for EuclideanMetric {
     }
 }
 
+// Hex steps between axial coordinates; only the first two components of a point are considered,
+// as (q, r)
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum HexMetric {}
+
+impl DistanceMetric for HexMetric {
+    fn hypercube_diagonal_magnitude<const DIM: usize>() -> f64 {
+        2.0
+    }
+
+    fn magnitude<const DIM: usize>(point: RealPoint<DIM>) -> f64 {
+        let q = point[0];
+        let r = if DIM > 1 { point[1] } else { 0.0 };
+
+        (q.abs() + r.abs() + (q + r).abs()) / 2.0
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum ManhattanMetric {}
 
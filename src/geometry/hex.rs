@@ -0,0 +1,224 @@
+use std::{fmt, ops::{Add, Mul, Sub}};
+use crate::{geometry::{Point, RealPoint}, utils};
+
+// Axial hex coordinates. The implicit third cube coordinate is `s = -q - r`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Hex {
+    pub q: i32,
+    pub r: i32,
+}
+
+impl Hex {
+    // Neighbour directions in counter-clockwise order, starting east of a pointy-top hex
+    pub const DIRECTIONS: [Hex; 6] = [
+        Hex::new(1, 0),
+        Hex::new(1, -1),
+        Hex::new(0, -1),
+        Hex::new(-1, 0),
+        Hex::new(-1, 1),
+        Hex::new(0, 1),
+    ];
+
+    pub const fn new(q: i32, r: i32) -> Self {
+        Self { q, r }
+    }
+
+    pub fn from_cube(q: i32, r: i32, s: i32) -> Self {
+        assert_eq!(q + r + s, 0, "Cube coordinates must sum to zero");
+
+        Self { q, r }
+    }
+
+    pub fn s(self) -> i32 {
+        -self.q - self.r
+    }
+
+    pub fn cube(self) -> [i32; 3] {
+        [self.q, self.r, self.s()]
+    }
+
+    // The axial coordinates as a lattice point, (q, r)
+    pub fn to_axial_point(self) -> Point<2> {
+        Point::new([self.q, self.r])
+    }
+
+    pub fn from_axial_point(point: Point<2>) -> Self {
+        Self { q: point[0], r: point[1] }
+    }
+
+    pub fn length(self) -> i32 {
+        (self.q.abs() + self.r.abs() + self.s().abs()) / 2
+    }
+
+    pub fn distance(self, other: Self) -> i32 {
+        (self - other).length()
+    }
+
+    pub fn neighbor(self, direction: usize) -> Self {
+        self + Self::DIRECTIONS[direction % 6]
+    }
+
+    pub fn neighbors(self) -> impl Iterator<Item = Hex> {
+        Self::DIRECTIONS.into_iter().map(move |direction| self + direction)
+    }
+
+    // Hexes at exactly `radius` steps, walking counter-clockwise from the south-west corner
+    pub fn ring(self, radius: u32) -> impl Iterator<Item = Hex> {
+        let radius = radius as i32;
+        let mut hexes = Vec::with_capacity(6 * radius.max(1) as usize);
+
+        if radius == 0 {
+            hexes.push(self);
+        } else {
+            let mut hex = self + Self::DIRECTIONS[4] * radius;
+
+            for direction in Self::DIRECTIONS {
+                for _ in 0..radius {
+                    hexes.push(hex);
+                    hex = hex + direction;
+                };
+            };
+        };
+
+        hexes.into_iter()
+    }
+
+    // The hex itself followed by every ring out to `radius`
+    pub fn spiral(self, radius: u32) -> impl Iterator<Item = Hex> {
+        (0..=radius).flat_map(move |ring_radius| self.ring(ring_radius))
+    }
+
+    // Every hex crossed by the straight line between the two centres, inclusive of both ends
+    pub fn line_to(self, other: Self) -> impl Iterator<Item = Hex> {
+        let steps = self.distance(other);
+
+        // Nudged off the centres so that lines along hex edges round consistently
+        let start = FractionalHex::new(self.q as f64 + 1e-6, self.r as f64 + 2e-6);
+        let end = FractionalHex::new(other.q as f64 + 1e-6, other.r as f64 + 2e-6);
+
+        (0..=steps).map(move |step| {
+            let bias = if steps == 0 { 0.0 } else { (step as f64) / (steps as f64) };
+
+            start.lerp(end, bias).round()
+        })
+    }
+}
+
+impl Add for Hex {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl Mul<i32> for Hex {
+    type Output = Self;
+
+    fn mul(self, scalar: i32) -> Self {
+        Self::new(self.q * scalar, self.r * scalar)
+    }
+}
+
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.q, self.r, self.s())
+    }
+}
+
+// Axial coordinates anywhere on the plane, rather than at hex centres
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct FractionalHex {
+    pub q: f64,
+    pub r: f64,
+}
+
+impl FractionalHex {
+    pub const fn new(q: f64, r: f64) -> Self {
+        Self { q, r }
+    }
+
+    pub fn s(self) -> f64 {
+        -self.q - self.r
+    }
+
+    pub fn to_axial_point(self) -> RealPoint<2> {
+        RealPoint::new([self.q, self.r])
+    }
+
+    pub fn lerp(self, other: Self, bias: f64) -> Self {
+        Self::new(utils::lerp(bias, self.q, other.q), utils::lerp(bias, self.r, other.r))
+    }
+
+    // The hex containing this position; rounds in cube space and then restores the constraint by
+    // recomputing whichever coordinate was rounded furthest
+    pub fn round(self) -> Hex {
+        let (q, r, s) = (self.q.round(), self.r.round(), self.s().round());
+        let (dq, dr, ds) = ((q - self.q).abs(), (r - self.r).abs(), (s - self.s()).abs());
+
+        if dq > dr && dq > ds {
+            Hex::new((-r - s) as i32, r as i32)
+        } else if dr > ds {
+            Hex::new(q as i32, (-q - s) as i32)
+        } else {
+            Hex::new(q as i32, r as i32)
+        }
+    }
+}
+
+impl From<Hex> for FractionalHex {
+    fn from(hex: Hex) -> Self {
+        Self::new(hex.q as f64, hex.r as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{FractionalHex, Hex};
+
+    #[test]
+    fn distance_and_neighbors() {
+        let origin = Hex::new(0, 0);
+
+        assert_eq!(Hex::new(3, -1).distance(origin), 3);
+        assert_eq!(Hex::from_cube(-2, 3, -1).distance(Hex::new(1, 1)), 3);
+
+        for neighbor in origin.neighbors() {
+            assert_eq!(neighbor.distance(origin), 1);
+        };
+    }
+
+    #[test]
+    fn rings_and_spirals() {
+        let center = Hex::new(2, -1);
+
+        assert_eq!(center.ring(0).collect::<Vec<_>>(), vec![center]);
+        assert_eq!(center.ring(2).count(), 12);
+        assert!(center.ring(3).all(|hex| hex.distance(center) == 3));
+        assert_eq!(center.spiral(2).count(), 19);
+        assert_eq!(center.spiral(1).nth(1), Some(Hex::new(1, 0)));
+    }
+
+    #[test]
+    fn line_and_round() {
+        let line: Vec<Hex> = Hex::new(0, 0).line_to(Hex::new(3, -3)).collect();
+
+        assert_eq!(line, vec![Hex::new(0, 0), Hex::new(1, -1), Hex::new(2, -2), Hex::new(3, -3)]);
+
+        let line: Vec<Hex> = Hex::new(-1, 2).line_to(Hex::new(3, 0)).collect();
+
+        assert_eq!(line.len(), 5);
+        assert!(line.windows(2).all(|pair| pair[0].distance(pair[1]) == 1));
+
+        assert_eq!(FractionalHex::new(0.4, 0.4).round(), Hex::new(0, 1));
+        assert_eq!(FractionalHex::new(0.2, -0.1).round(), Hex::new(0, 0));
+    }
+}
@@ -0,0 +1,121 @@
+use crate::geometry::{FractionalHex, Hex, Point, RealPoint};
+
+const SQRT_3: f64 = 1.732_050_807_568_877_2;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum HexOrientation {
+    // Rows of hexes, with a vertex at the top; offset coordinates shift odd rows right
+    Pointy,
+    // Columns of hexes, with an edge at the top; offset coordinates shift odd columns down
+    Flat,
+}
+
+// Places hexes on the plane. `size` is the distance from a hex's centre to its vertices, and
+// `origin` is the centre of the hex at (0, 0).
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct HexLayout {
+    pub orientation: HexOrientation,
+    pub size: f64,
+    pub origin: RealPoint<2>,
+}
+
+impl HexLayout {
+    pub fn new(orientation: HexOrientation, size: f64) -> Self {
+        Self { orientation, size, origin: RealPoint::new([0.0, 0.0]) }
+    }
+
+    pub fn with_origin(mut self, origin: RealPoint<2>) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    pub fn center(&self, hex: Hex) -> RealPoint<2> {
+        let (q, r) = (hex.q as f64, hex.r as f64);
+
+        let offset = match self.orientation {
+            HexOrientation::Pointy => RealPoint::new([SQRT_3 * q + SQRT_3 / 2.0 * r, 1.5 * r]),
+            HexOrientation::Flat => RealPoint::new([1.5 * q, SQRT_3 / 2.0 * q + SQRT_3 * r]),
+        };
+
+        self.origin + offset * self.size
+    }
+
+    pub fn fractional_hex_at(&self, point: RealPoint<2>) -> FractionalHex {
+        let point = (point - self.origin) / self.size;
+        let (x, y) = (point[0], point[1]);
+
+        match self.orientation {
+            HexOrientation::Pointy => FractionalHex::new(SQRT_3 / 3.0 * x - y / 3.0, 2.0 / 3.0 * y),
+            HexOrientation::Flat => FractionalHex::new(2.0 / 3.0 * x, -x / 3.0 + SQRT_3 / 3.0 * y),
+        }
+    }
+
+    pub fn hex_at(&self, point: RealPoint<2>) -> Hex {
+        self.fractional_hex_at(point).round()
+    }
+
+    // Vertices in counter-clockwise order, as seen with y pointing up
+    pub fn corners(&self, hex: Hex) -> [RealPoint<2>; 6] {
+        let center = self.center(hex);
+        let start_angle = match self.orientation {
+            HexOrientation::Pointy => 30.0f64,
+            HexOrientation::Flat => 0.0f64,
+        };
+
+        [0, 1, 2, 3, 4, 5].map(|corner| {
+            let angle = (start_angle + 60.0 * (corner as f64)).to_radians();
+
+            center + RealPoint::new([angle.cos(), angle.sin()]) * self.size
+        })
+    }
+
+    // Offset coordinates, suitable for storing hexes in a rectangular `Grid`: (column, row)
+    pub fn to_offset(&self, hex: Hex) -> Point<2> {
+        match self.orientation {
+            HexOrientation::Pointy => Point::new([hex.q + (hex.r - (hex.r & 1)) / 2, hex.r]),
+            HexOrientation::Flat => Point::new([hex.q, hex.r + (hex.q - (hex.q & 1)) / 2]),
+        }
+    }
+
+    pub fn from_offset(&self, offset: Point<2>) -> Hex {
+        let (column, row) = (offset[0], offset[1]);
+
+        match self.orientation {
+            HexOrientation::Pointy => Hex::new(column - (row - (row & 1)) / 2, row),
+            HexOrientation::Flat => Hex::new(column, row - (column - (column & 1)) / 2),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{EuclideanMetric, Hex, HexLayout, HexOrientation, Point, RealPoint};
+
+    #[test]
+    fn round_trips() {
+        for orientation in [HexOrientation::Pointy, HexOrientation::Flat] {
+            let layout = HexLayout::new(orientation, 2.5).with_origin(RealPoint::new([3.0, -1.0]));
+
+            for hex in Hex::new(1, -2).spiral(3) {
+                assert_eq!(layout.hex_at(layout.center(hex)), hex);
+                assert_eq!(layout.from_offset(layout.to_offset(hex)), hex);
+
+                for corner in layout.corners(hex) {
+                    let distance = (corner - layout.center(hex)).magnitude::<EuclideanMetric>();
+
+                    assert!((distance - 2.5).abs() < 1e-9);
+                };
+            };
+        };
+    }
+
+    #[test]
+    fn offsets() {
+        let pointy = HexLayout::new(HexOrientation::Pointy, 1.0);
+        let flat = HexLayout::new(HexOrientation::Flat, 1.0);
+
+        assert_eq!(pointy.to_offset(Hex::new(-1, 3)), Point::new([0, 3]));
+        assert_eq!(flat.to_offset(Hex::new(3, -1)), Point::new([3, 0]));
+        assert_eq!(pointy.from_offset(Point::new([0, -1])), Hex::new(1, -1));
+    }
+}
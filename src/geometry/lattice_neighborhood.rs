@@ -1,17 +1,98 @@
-use crate::geometry::Point;
+use crate::geometry::{DistanceMetric, ManhattanMetric, Point, RealPoint};
 
+// Which lattice points count as adjacent: those sharing a face, or those sharing any corner. In 2D
+// these are 4- and 8-connectivity.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Adjacency {
+    VonNeumann,
+    Moore,
+}
+
+impl Adjacency {
+    pub fn neighbors<const DIM: usize>(self, point: Point<DIM>) -> LatticeNeighborhood<DIM> {
+        match self {
+            Adjacency::VonNeumann => point.von_neumann_neighbors(1),
+            Adjacency::Moore => point.neighbors(),
+        }
+    }
+}
+
+// Chooses the points adjacent to each point, for searches which step between them. Implemented by
+// `Adjacency` and by any closure returning a `LatticeNeighborhood`, such as
+// `|point| point.moore_neighbors(2)`.
+pub trait Neighborhood<const DIM: usize> {
+    fn neighbors(&self, point: Point<DIM>) -> LatticeNeighborhood<DIM>;
+}
+
+impl<const DIM: usize> Neighborhood<DIM> for Adjacency {
+    fn neighbors(&self, point: Point<DIM>) -> LatticeNeighborhood<DIM> {
+        Adjacency::neighbors(*self, point)
+    }
+}
+
+impl<const DIM: usize, F> Neighborhood<DIM> for F where F: Fn(Point<DIM>) -> LatticeNeighborhood<DIM> {
+    fn neighbors(&self, point: Point<DIM>) -> LatticeNeighborhood<DIM> {
+        self(point)
+    }
+}
+
+// Lattice points around an origin, visited in a fixed order: offsets run from `-radius` to `radius`
+// along each axis, with the first axis varying fastest. Points farther than `radius` from the
+// origin under the neighbourhood's metric are skipped; Moore blocks have no metric and skip nothing.
 pub struct LatticeNeighborhood<const DIM: usize> {
     origin: Point<DIM>,
     include_self: bool,
+    radius: i32,
+    max_magnitude: f64,
+    magnitude: Option<fn(RealPoint<DIM>) -> f64>,
     current_index: usize,
 }
 
 impl<const DIM: usize> LatticeNeighborhood<DIM> {
-    const NUM_NEIGHBORS: usize = 3usize.pow(DIM as u32);
-    const SELF_INDEX: usize = Self::NUM_NEIGHBORS / 2;
-
+    // The 3^DIM Moore block
     pub fn new(origin: Point<DIM>, include_self: bool) -> Self {
-        Self { origin, include_self, current_index: 0 }
+        Self::moore(origin, 1, include_self)
+    }
+
+    // The (2 * radius + 1)^DIM block
+    pub fn moore(origin: Point<DIM>, radius: u32, include_self: bool) -> Self {
+        Self {
+            origin,
+            include_self,
+            radius: radius as i32,
+            max_magnitude: radius as f64,
+            magnitude: None,
+            current_index: 0,
+        }
+    }
+
+    // Points reachable in at most `radius` orthogonal steps
+    pub fn von_neumann(origin: Point<DIM>, radius: u32, include_self: bool) -> Self {
+        Self::within::<ManhattanMetric>(origin, radius as f64, include_self)
+    }
+
+    // Points whose distance from the origin under `T` is at most `radius`
+    pub fn within<T: DistanceMetric>(origin: Point<DIM>, radius: f64, include_self: bool) -> Self {
+        Self {
+            origin,
+            include_self,
+            radius: radius.max(0.0).floor() as i32,
+            max_magnitude: radius,
+            magnitude: Some(T::magnitude::<DIM>),
+            current_index: 0,
+        }
+    }
+
+    fn side(&self) -> usize {
+        (2 * self.radius + 1) as usize
+    }
+
+    fn num_points(&self) -> usize {
+        self.side().pow(DIM as u32)
+    }
+
+    fn self_index(&self) -> usize {
+        self.num_points() / 2
     }
 }
 
@@ -19,35 +100,42 @@ impl<const DIM: usize> Iterator for LatticeNeighborhood<DIM> {
     type Item = Point<DIM>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current_index >= Self::NUM_NEIGHBORS {
-            None
-        } else {
-            if !self.include_self && self.current_index == Self::SELF_INDEX {
-                self.current_index += 1;
+        let side = self.side();
 
-                return self.next();
+        while self.current_index < self.num_points() {
+            let idx = self.current_index;
+
+            self.current_index += 1;
+
+            if !self.include_self && idx == self.self_index() {
+                continue;
             };
 
-            let mut coordinates = [0i32; DIM];
+            let mut offset = Point::new([0i32; DIM]);
+            let mut remainder = idx;
 
             for dim in 0..DIM {
-                let idx = self.current_index as i32;
-                let divisor = 3i32.pow(dim as u32);
-                let modulus = divisor * 3;
+                offset[dim] = (remainder % side) as i32 - self.radius;
+                remainder /= side;
+            };
 
-                coordinates[dim] = (idx % modulus) / divisor - 1;
+            let is_within = match self.magnitude {
+                Some(magnitude) => magnitude(offset.to_real_point()) <= self.max_magnitude,
+                None => true,
             };
 
-            self.current_index += 1;
+            if is_within {
+                return Some(self.origin + offset);
+            };
+        };
 
-            Some(self.origin + Point::new(coordinates))
-        }
+        None
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::geometry::Point;
+    use crate::geometry::{Adjacency, EuclideanMetric, Point};
 
     #[test]
     fn lattice_neighborhood_2d() {
@@ -152,4 +240,46 @@ mod tests {
             Point::new([6, 3, 9]),
         ]);
     }
+
+    #[test]
+    fn von_neumann_and_moore_radius() {
+        let point = Point::new([0, 0]);
+
+        assert_eq!(point.von_neumann_neighbors(1).collect::<Vec<_>>(), vec![
+            Point::new([0, -1]),
+            Point::new([-1, 0]),
+            Point::new([1, 0]),
+            Point::new([0, 1]),
+        ]);
+
+        assert_eq!(point.von_neumann_neighbors(2).count(), 12);
+        assert_eq!(point.moore_neighbors(2).count(), 24);
+
+        let point = Point::new([1, 2, 3]);
+
+        assert_eq!(point.moore_neighbors(1).collect::<Vec<_>>(), point.neighbors().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn within() {
+        let point = Point::new([3, 3]);
+        let ball: Vec<Point<2>> = point.within::<EuclideanMetric>(1.5).collect();
+
+        assert_eq!(ball, point.neighbors_and_self().collect::<Vec<_>>());
+        assert_eq!(point.within::<EuclideanMetric>(2.0).count(), 13);
+        assert_eq!(point.within::<EuclideanMetric>(0.0).collect::<Vec<_>>(), vec![point]);
+    }
+
+    #[test]
+    fn adjacency() {
+        let point = Point::new([2, 2]);
+
+        assert_eq!(Adjacency::Moore.neighbors(point).count(), 8);
+        assert_eq!(Adjacency::VonNeumann.neighbors(point).collect::<Vec<_>>(), vec![
+            Point::new([2, 1]),
+            Point::new([1, 2]),
+            Point::new([3, 2]),
+            Point::new([2, 3]),
+        ]);
+    }
 }
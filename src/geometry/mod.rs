@@ -1,20 +1,35 @@
+mod aabb;
+mod bresenham_line;
 mod distance_metric;
+mod hex;
+mod hex_layout;
 mod lattice_neighborhood;
 mod point;
+mod ray_march;
+mod real_aabb;
 mod real_point;
+mod supercover_line;
 mod vertex_neighborhood;
 
+pub use aabb::{Aabb, AabbPoints};
+pub use bresenham_line::BresenhamLine;
 pub use distance_metric::{
     ChebyshevMetric,
     DistanceMetric,
     EuclideanMetric,
+    HexMetric,
     ManhattanMetric,
     MinkowskiMetric,
 };
 
-pub use lattice_neighborhood::LatticeNeighborhood;
+pub use hex::{FractionalHex, Hex};
+pub use hex_layout::{HexLayout, HexOrientation};
+pub use lattice_neighborhood::{Adjacency, LatticeNeighborhood, Neighborhood};
 pub use point::Point;
+pub use ray_march::{RayMarch, RayStep};
+pub use real_aabb::RealAabb;
 pub use real_point::RealPoint;
+pub use supercover_line::SupercoverLine;
 pub use vertex_neighborhood::VertexNeighborhood;
 
 #[macro_export]
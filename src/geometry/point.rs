@@ -1,7 +1,10 @@
 use std::{fmt, mem, ops::*, slice};
-use crate::{point_ops_impl, geometry::{LatticeNeighborhood, RealPoint}};
+use crate::{
+    point_ops_impl,
+    geometry::{BresenhamLine, DistanceMetric, LatticeNeighborhood, RealPoint, SupercoverLine},
+};
 
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 #[repr(transparent)]
 pub struct Point<const DIM: usize> {
     coordinates: [i32; DIM],
@@ -28,6 +31,27 @@ impl<const DIM: usize> Point<DIM> {
         LatticeNeighborhood::new(self, true)
     }
 
+    pub fn moore_neighbors(self, radius: u32) -> LatticeNeighborhood<DIM> {
+        LatticeNeighborhood::moore(self, radius, false)
+    }
+
+    pub fn von_neumann_neighbors(self, radius: u32) -> LatticeNeighborhood<DIM> {
+        LatticeNeighborhood::von_neumann(self, radius, false)
+    }
+
+    // Includes the point itself
+    pub fn within<T: DistanceMetric>(self, radius: f64) -> LatticeNeighborhood<DIM> {
+        LatticeNeighborhood::within::<T>(self, radius, true)
+    }
+
+    pub fn line_to(self, end: Self) -> BresenhamLine<DIM> {
+        BresenhamLine::new(self, end)
+    }
+
+    pub fn supercover_line_to(self, end: Self) -> SupercoverLine<DIM> {
+        SupercoverLine::new(self, end)
+    }
+
     pub fn sum(self) -> i32 {
         self.iter().fold(0i32, |acc, &elem| acc + elem)
     }
@@ -0,0 +1,126 @@
+use crate::geometry::{EuclideanMetric, Point, RealPoint};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct RayStep<const DIM: usize> {
+    pub cell: Point<DIM>,
+    // Euclidean distance along the ray at which it enters the cell; zero for the starting cell
+    pub distance: f64,
+}
+
+// Every unit cell crossed by a ray, in order (Amanatides & Woo). Cell `p` spans `p` to `p + 1`
+// along each axis. Where the ray passes exactly through an edge or corner, it steps along the lower
+// axes first, so consecutive cells always share a face.
+pub struct RayMarch<const DIM: usize> {
+    cell: Point<DIM>,
+    steps: Point<DIM>,
+    boundary_distances: RealPoint<DIM>,
+    cell_distances: RealPoint<DIM>,
+    max_distance: f64,
+    next_distance: Option<f64>,
+}
+
+impl<const DIM: usize> RayMarch<DIM> {
+    pub fn new(origin: RealPoint<DIM>, direction: RealPoint<DIM>, max_distance: f64) -> Self {
+        let length = direction.magnitude::<EuclideanMetric>();
+        let cell = origin.floor().to_lattice_point();
+
+        let mut steps = Point::new([0; DIM]);
+        let mut boundary_distances = RealPoint::new([f64::INFINITY; DIM]);
+        let mut cell_distances = RealPoint::new([f64::INFINITY; DIM]);
+
+        for axis in 0..DIM {
+            let component = if length > 0.0 { direction[axis] / length } else { 0.0 };
+
+            if component > 0.0 {
+                steps[axis] = 1;
+                boundary_distances[axis] = ((cell[axis] + 1) as f64 - origin[axis]) / component;
+                cell_distances[axis] = 1.0 / component;
+            } else if component < 0.0 {
+                steps[axis] = -1;
+                boundary_distances[axis] = (origin[axis] - cell[axis] as f64) / -component;
+                cell_distances[axis] = -1.0 / component;
+            };
+        };
+
+        Self {
+            cell,
+            steps,
+            boundary_distances,
+            cell_distances,
+            max_distance,
+            next_distance: Some(0.0),
+        }
+    }
+}
+
+impl<const DIM: usize> Iterator for RayMarch<DIM> {
+    type Item = RayStep<DIM>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let distance = self.next_distance?;
+        let step = RayStep { cell: self.cell, distance };
+
+        let mut axis = 0;
+
+        for candidate in 1..DIM {
+            if self.boundary_distances[candidate] < self.boundary_distances[axis] {
+                axis = candidate;
+            };
+        };
+
+        let boundary_distance = self.boundary_distances[axis];
+
+        // A zero direction never reaches a boundary, which would otherwise pass an infinite limit
+        if boundary_distance.is_finite() && boundary_distance <= self.max_distance {
+            self.cell[axis] += self.steps[axis];
+            self.boundary_distances[axis] += self.cell_distances[axis];
+            self.next_distance = Some(boundary_distance);
+        } else {
+            self.next_distance = None;
+        };
+
+        Some(step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Point, RayMarch, RealPoint};
+
+    #[test]
+    fn ray_march() {
+        let ray = RayMarch::new(RealPoint::new([0.5, 0.25]), RealPoint::new([2.0, 1.0]), 3.0);
+        let steps: Vec<(Point<2>, f64)> = ray
+            .map(|step| (step.cell, step.distance))
+            .collect();
+
+        let cells: Vec<Point<2>> = steps.iter().map(|&(cell, _)| cell).collect();
+
+        // Passes exactly through the corner at (2, 1), so both cells touching it are visited
+        assert_eq!(cells, vec![
+            Point::new([0, 0]),
+            Point::new([1, 0]),
+            Point::new([2, 0]),
+            Point::new([2, 1]),
+            Point::new([3, 1]),
+        ]);
+
+        // Enters x = 1 after travelling half a cell horizontally
+        assert!((steps[1].1 - 0.25 * 5.0f64.sqrt()).abs() < 1e-9);
+        assert!(steps.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+
+        let backwards: Vec<Point<1>> = RayMarch::new(RealPoint::new([0.5]), RealPoint::new([-1.0]), 2.0)
+            .map(|step| step.cell)
+            .collect();
+
+        assert_eq!(backwards, vec![Point::new([0]), Point::new([-1]), Point::new([-2])]);
+    }
+
+    #[test]
+    fn zero_direction() {
+        let ray = RayMarch::new(RealPoint::new([1.5, -0.5]), RealPoint::new([0.0, 0.0]), f64::INFINITY);
+        let steps: Vec<Point<2>> = ray.map(|step| step.cell).collect();
+
+        assert_eq!(steps, vec![Point::new([1, -1])]);
+    }
+}
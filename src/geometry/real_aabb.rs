@@ -0,0 +1,123 @@
+use crate::geometry::{Aabb, Point, RealPoint};
+
+// Closed box, from `min` to `max` inclusive along every axis
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct RealAabb<const DIM: usize> {
+    pub min: RealPoint<DIM>,
+    pub max: RealPoint<DIM>,
+}
+
+impl<const DIM: usize> RealAabb<DIM> {
+    pub const fn new(min: RealPoint<DIM>, max: RealPoint<DIM>) -> Self {
+        Self { min, max }
+    }
+
+    pub fn size(&self) -> RealPoint<DIM> {
+        let mut size = self.max - self.min;
+
+        for length in size.iter_mut() {
+            *length = length.max(0.0);
+        };
+
+        size
+    }
+
+    pub fn center(&self) -> RealPoint<DIM> {
+        (self.min + self.max) * 0.5
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..DIM).any(|axis| self.max[axis] < self.min[axis])
+    }
+
+    pub fn volume(&self) -> f64 {
+        self.size().iter().product()
+    }
+
+    pub fn contains(&self, point: RealPoint<DIM>) -> bool {
+        (0..DIM).all(|axis| point[axis] >= self.min[axis] && point[axis] <= self.max[axis])
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut intersection = *self;
+
+        for axis in 0..DIM {
+            intersection.min[axis] = self.min[axis].max(other.min[axis]);
+            intersection.max[axis] = self.max[axis].min(other.max[axis]);
+        };
+
+        if intersection.is_empty() { None } else { Some(intersection) }
+    }
+
+    // Smallest box containing both; empty boxes are ignored
+    pub fn union(&self, other: &Self) -> Self {
+        if self.is_empty() {
+            return *other;
+        };
+
+        if other.is_empty() {
+            return *self;
+        };
+
+        let mut union = *self;
+
+        for axis in 0..DIM {
+            union.min[axis] = self.min[axis].min(other.min[axis]);
+            union.max[axis] = self.max[axis].max(other.max[axis]);
+        };
+
+        union
+    }
+
+    // Grows the box by `amount` on every side; negative amounts shrink it
+    pub fn expand(&self, amount: f64) -> Self {
+        Self { min: self.min - amount, max: self.max + amount }
+    }
+
+    pub fn clamp(&self, point: RealPoint<DIM>) -> RealPoint<DIM> {
+        let mut clamped = point;
+
+        for axis in 0..DIM {
+            clamped[axis] = point[axis].max(self.min[axis]).min(self.max[axis]);
+        };
+
+        clamped
+    }
+
+    pub fn split(&self, axis: usize, at: f64) -> (Self, Self) {
+        let at = at.max(self.min[axis]).min(self.max[axis]);
+        let (mut lower, mut upper) = (*self, *self);
+
+        lower.max[axis] = at;
+        upper.min[axis] = at;
+
+        (lower, upper)
+    }
+
+    // The lattice points lying inside
+    pub fn lattice_aabb(&self) -> Aabb<DIM> {
+        Aabb::new(self.min.ceil().to_lattice_point(), self.max.floor().to_lattice_point() + Point::new([1; DIM]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Aabb, Point, RealAabb, RealPoint};
+
+    #[test]
+    fn real_aabb() {
+        let a = RealAabb::new(RealPoint::new([0.0, 0.0]), RealPoint::new([2.0, 1.0]));
+        let b = RealAabb::new(RealPoint::new([1.5, 0.5]), RealPoint::new([3.0, 3.0]));
+
+        assert_eq!(a.volume(), 2.0);
+        assert!(a.contains(RealPoint::new([2.0, 1.0])));
+        assert_eq!(a.intersection(&b).unwrap().volume(), 0.25);
+        assert_eq!(a.union(&b).volume(), 9.0);
+        assert_eq!(a.clamp(RealPoint::new([5.0, -1.0])), RealPoint::new([2.0, 0.0]));
+        assert_eq!(a.expand(0.5).center(), a.center());
+
+        let lattice = RealAabb::new(RealPoint::new([-0.5, 0.2]), RealPoint::new([1.0, 2.7])).lattice_aabb();
+
+        assert_eq!(lattice, Aabb::new(Point::new([0, 1]), Point::new([2, 3])));
+    }
+}
@@ -0,0 +1,63 @@
+use crate::geometry::{EuclideanMetric, Point, RayMarch};
+
+// Every lattice point whose unit cell is crossed by the segment between the centres of `start` and
+// `end`, in order. Unlike `BresenhamLine`, this never cuts corners: consecutive points differ along
+// exactly one axis.
+pub struct SupercoverLine<const DIM: usize> {
+    ray: RayMarch<DIM>,
+    end: Point<DIM>,
+    is_done: bool,
+}
+
+impl<const DIM: usize> SupercoverLine<DIM> {
+    pub fn new(start: Point<DIM>, end: Point<DIM>) -> Self {
+        // Cells are centred on lattice points here, rather than spanning them
+        let origin = start.to_real_point() + 0.5;
+        let direction = (end - start).to_real_point();
+        let length = direction.magnitude::<EuclideanMetric>();
+
+        Self { ray: RayMarch::new(origin, direction, length), end, is_done: false }
+    }
+}
+
+impl<const DIM: usize> Iterator for SupercoverLine<DIM> {
+    type Item = Point<DIM>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        };
+
+        let cell = self.ray.next()?.cell;
+
+        self.is_done = cell == self.end;
+
+        Some(cell)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::Point;
+
+    #[test]
+    fn supercover_line() {
+        let line: Vec<Point<2>> = Point::new([0, 0]).supercover_line_to(Point::new([2, 2])).collect();
+
+        assert_eq!(line, vec![
+            Point::new([0, 0]),
+            Point::new([1, 0]),
+            Point::new([1, 1]),
+            Point::new([2, 1]),
+            Point::new([2, 2]),
+        ]);
+
+        let start = Point::new([-3, 4, 1]);
+        let end = Point::new([5, -2, 2]);
+        let line: Vec<Point<3>> = start.supercover_line_to(end).collect();
+
+        assert_eq!(line.first(), Some(&start));
+        assert_eq!(line.last(), Some(&end));
+        assert!(line.windows(2).all(|pair| (pair[1] - pair[0]).abs().sum() == 1));
+    }
+}
@@ -3,8 +3,19 @@
 #![feature(generic_const_exprs)]
 #![feature(specialization)]
 
+pub mod analysis;
+pub mod biome;
+pub mod cellular;
 pub mod collections;
+pub mod color;
+pub mod dungeon;
+pub mod fov;
 pub mod geometry;
 pub mod noise;
+pub mod pathfinding;
 pub mod random;
+pub mod regions;
+#[cfg(feature = "image")]
+pub mod render;
+pub mod terrain;
 pub mod utils;
@@ -0,0 +1,28 @@
+use crate::{color::ColorRamp, geometry::RealPoint, noise::{NoiseNode, VectorNoiseNode}};
+
+// Maps a scalar node through a colour ramp, producing colours rather than scalar values
+pub struct ColorNode<'a, const DIM: usize, T> where T: NoiseNode<DIM> {
+    source: &'a T,
+    ramp: &'a ColorRamp,
+}
+
+impl<'a, const DIM: usize, T> ColorNode<'a, DIM, T> where T: NoiseNode<DIM> {
+    pub fn new(source: &'a T, ramp: &'a ColorRamp) -> Self {
+        Self { source, ramp }
+    }
+
+    // sRGB-encoded components in [0.0, 1.0]
+    pub fn rgb_at(&self, point: RealPoint<DIM>) -> [f64; 3] {
+        self.ramp.rgb_at(self.source.value_at(point))
+    }
+
+    pub fn rgba_at(&self, point: RealPoint<DIM>) -> [u8; 4] {
+        self.ramp.rgba_at(self.source.value_at(point))
+    }
+}
+
+impl<'a, const DIM: usize, T> VectorNoiseNode<DIM, 3> for ColorNode<'a, DIM, T> where T: NoiseNode<DIM> {
+    fn vector_at(&self, point: RealPoint<DIM>) -> RealPoint<3> {
+        RealPoint::new(self.rgb_at(point))
+    }
+}
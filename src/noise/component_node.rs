@@ -0,0 +1,23 @@
+use crate::{geometry::RealPoint, noise::{NoiseNode, VectorNoiseNode}};
+
+pub struct ComponentNode<'a, const IN: usize, const OUT: usize, T>
+where T: VectorNoiseNode<IN, OUT> {
+    source: &'a T,
+    component: usize,
+}
+
+impl<'a, const IN: usize, const OUT: usize, T> ComponentNode<'a, IN, OUT, T>
+where T: VectorNoiseNode<IN, OUT> {
+    pub fn new(source: &'a T, component: usize) -> Self {
+        assert!(component < OUT, "Component {} out of range for a {}-vector", component, OUT);
+
+        Self { source, component }
+    }
+}
+
+impl<'a, const IN: usize, const OUT: usize, T> NoiseNode<IN> for ComponentNode<'a, IN, OUT, T>
+where T: VectorNoiseNode<IN, OUT> {
+    fn value_at(&self, point: RealPoint<IN>) -> f64 {
+        self.source.vector_at(point)[self.component]
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{geometry::RealPoint, noise::{NoiseNode, VectorNoiseNode}};
+
+const EPSILON: f64 = 1e-4;
+
+fn partial_derivative<const DIM: usize, T>(node: &T, point: RealPoint<DIM>, dim: usize) -> f64
+where T: NoiseNode<DIM> {
+    let mut offset = RealPoint::new([0.0f64; DIM]);
+    offset[dim] = EPSILON;
+
+    (node.value_at(point + offset) - node.value_at(point - offset)) / (2.0 * EPSILON)
+}
+
+// Divergence-free flow field formed by rotating the gradient of a scalar potential by 90 degrees
+pub struct CurlNode2<'a, T> where T: NoiseNode<2> {
+    potential: &'a T,
+}
+
+impl<'a, T> CurlNode2<'a, T> where T: NoiseNode<2> {
+    pub fn new(potential: &'a T) -> Self {
+        Self { potential }
+    }
+}
+
+impl<'a, T> VectorNoiseNode<2, 2> for CurlNode2<'a, T> where T: NoiseNode<2> {
+    fn vector_at(&self, point: RealPoint<2>) -> RealPoint<2> {
+        let dx = partial_derivative(self.potential, point, 0);
+        let dy = partial_derivative(self.potential, point, 1);
+
+        RealPoint::new([dy, -dx])
+    }
+}
+
+// Divergence-free flow field formed by taking the curl of a vector potential. Each component of the
+// potential should be an independent node, e.g. three `PerlinNode`s built from `Seed::derive`.
+pub struct CurlNode3<'a, T> where T: NoiseNode<3> {
+    potentials: [&'a T; 3],
+}
+
+impl<'a, T> CurlNode3<'a, T> where T: NoiseNode<3> {
+    pub fn new(potentials: [&'a T; 3]) -> Self {
+        Self { potentials }
+    }
+}
+
+impl<'a, T> VectorNoiseNode<3, 3> for CurlNode3<'a, T> where T: NoiseNode<3> {
+    fn vector_at(&self, point: RealPoint<3>) -> RealPoint<3> {
+        let [px, py, pz] = self.potentials;
+
+        RealPoint::new([
+            partial_derivative(pz, point, 1) - partial_derivative(py, point, 2),
+            partial_derivative(px, point, 2) - partial_derivative(pz, point, 0),
+            partial_derivative(py, point, 0) - partial_derivative(px, point, 1),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{EuclideanMetric, RealPoint},
+        noise::{CurlNode2, CurlNode3, PerlinNode, VectorNoiseNode},
+        random::{Seed, Wyhash},
+    };
+
+    const STEP: f64 = 1e-3;
+
+    fn divergence<const DIM: usize, T>(node: &T, point: RealPoint<DIM>) -> f64
+    where T: VectorNoiseNode<DIM, DIM> {
+        (0..DIM).map(|dim| {
+            let mut offset = RealPoint::new([0.0f64; DIM]);
+            offset[dim] = STEP;
+
+            (node.vector_at(point + offset)[dim] - node.vector_at(point - offset)[dim]) / (2.0 * STEP)
+        }).sum()
+    }
+
+    #[test]
+    fn curl_2d_is_divergence_free() {
+        let perlin: PerlinNode<2, Wyhash> = PerlinNode::new(&Seed::DEFAULT_SEED);
+        let curl = CurlNode2::new(&perlin);
+
+        for idx in 0..32 {
+            let point = RealPoint::new([(idx as f64) * 0.37, (idx as f64) * -0.21 + 3.0]);
+
+            assert!(curl.vector_at(point).magnitude::<EuclideanMetric>().is_finite());
+            assert!(divergence(&curl, point).abs() < 1e-3);
+        };
+    }
+
+    #[test]
+    fn curl_3d_is_divergence_free() {
+        let seed = Seed::DEFAULT_SEED;
+        let px: PerlinNode<3, Wyhash> = PerlinNode::new(&seed.derive(0));
+        let py: PerlinNode<3, Wyhash> = PerlinNode::new(&seed.derive(1));
+        let pz: PerlinNode<3, Wyhash> = PerlinNode::new(&seed.derive(2));
+        let curl = CurlNode3::new([&px, &py, &pz]);
+
+        for idx in 0..32 {
+            let point = RealPoint::new([(idx as f64) * 0.37, (idx as f64) * -0.21 + 3.0, (idx as f64) * 0.13]);
+
+            assert!(divergence(&curl, point).abs() < 1e-3);
+        };
+    }
+}
@@ -0,0 +1,20 @@
+use crate::{geometry::RealPoint, noise::{NoiseNode, VectorNoiseNode}};
+
+pub struct DotNode<'a, const IN: usize, const OUT: usize, T> where T: VectorNoiseNode<IN, OUT> {
+    source: &'a T,
+    direction: RealPoint<OUT>,
+}
+
+impl<'a, const IN: usize, const OUT: usize, T> DotNode<'a, IN, OUT, T>
+where T: VectorNoiseNode<IN, OUT> {
+    pub fn new(source: &'a T, direction: RealPoint<OUT>) -> Self {
+        Self { source, direction }
+    }
+}
+
+impl<'a, const IN: usize, const OUT: usize, T> NoiseNode<IN> for DotNode<'a, IN, OUT, T>
+where T: VectorNoiseNode<IN, OUT> {
+    fn value_at(&self, point: RealPoint<IN>) -> f64 {
+        self.source.vector_at(point).dot_prod(self.direction)
+    }
+}
@@ -0,0 +1,132 @@
+use std::f64::consts::{PI, TAU};
+use crate::{
+    geometry::RealPoint,
+    noise::{impulse_scatter::ImpulseScatter, NoiseNode},
+    random::{HashFn, Seed},
+    utils,
+};
+
+// Sparse convolution noise using Gabor kernels: sinusoids of a fixed frequency windowed by a
+// Gaussian of the given bandwidth. The orientation node maps [0.0, 1.0] onto angles in [0, PI),
+// evaluated at each kernel's position; a `ConstNode` gives uniformly oriented noise.
+pub struct GaborNode<'a, T, U> where T: HashFn, U: NoiseNode<2> {
+    scatter: ImpulseScatter<2, T>,
+    orientation: &'a U,
+    frequency: f64,
+    bandwidth: f64,
+    impulses_per_cell: usize,
+    std_dev: f64,
+}
+
+impl<'a, T, U> GaborNode<'a, T, U> where T: HashFn, U: NoiseNode<2> {
+    pub fn new(
+        seed: &Seed,
+        orientation: &'a U,
+        frequency: f64,
+        bandwidth: f64,
+        impulses_per_cell: usize,
+    ) -> Self {
+        // Variance of the kernel sum for uniformly distributed weights in [-1.0, 1.0]
+        let ratio = frequency / bandwidth;
+        let variance = (impulses_per_cell as f64) / 12.0 * (1.0 + (-2.0 * PI * ratio * ratio).exp());
+
+        Self {
+            scatter: ImpulseScatter::new(seed),
+            orientation,
+            frequency,
+            bandwidth,
+            impulses_per_cell,
+            std_dev: variance.sqrt(),
+        }
+    }
+
+    // Kernels are truncated at the radius where the Gaussian envelope falls to e^-PI, which also
+    // sets the size of each lattice cell
+    fn kernel_radius(&self) -> f64 {
+        1.0 / self.bandwidth
+    }
+
+    fn kernel(&self, offset: RealPoint<2>, angle: f64) -> f64 {
+        let (sin, cos) = angle.sin_cos();
+        let envelope = (-PI * self.bandwidth * self.bandwidth * offset.dot_prod(offset)).exp();
+        let carrier = (TAU * self.frequency * (offset[0] * cos + offset[1] * sin)).cos();
+
+        envelope * carrier
+    }
+
+    pub fn raw_value_at(&self, point: RealPoint<2>) -> f64 {
+        let radius = self.kernel_radius();
+        let cell_point = point / radius;
+
+        let value: f64 = cell_point.to_lattice_point().neighbors_and_self().map(|cell| {
+            self.scatter.impulses(cell, self.impulses_per_cell).iter().map(|impulse| {
+                let position = impulse.position * radius;
+                let offset = point - position;
+
+                if offset.dot_prod(offset) >= radius * radius {
+                    0.0
+                } else {
+                    let angle = self.orientation.value_at(position) * PI;
+
+                    impulse.weight * self.kernel(offset, angle)
+                }
+            }).sum::<f64>()
+        }).sum();
+
+        value / self.std_dev
+    }
+}
+
+impl<'a, T, U> NoiseNode<2> for GaborNode<'a, T, U> where T: HashFn, U: NoiseNode<2> {
+    fn value_at(&self, point: RealPoint<2>) -> f64 {
+        utils::neg_unit_to_unit((self.raw_value_at(point) / 3.0).clamp(-1.0, 1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        analysis::{SampleRegion, Summary},
+        geometry::RealPoint,
+        noise::{ConstNode, GaborNode, NoiseNode},
+        random::{Seed, Wyhash},
+    };
+
+    fn directional_variance<T: NoiseNode<2>>(node: &T, direction: RealPoint<2>) -> f64 {
+        let region = SampleRegion::new(RealPoint::new([0.5, 0.5]), 0.31, [24, 24]);
+        let differences: Vec<f64> = region.points().map(|point| {
+            node.value_at(point + direction * 0.05) - node.value_at(point)
+        }).collect();
+
+        Summary::from_samples(&differences).variance
+    }
+
+    #[test]
+    fn oriented() {
+        let horizontal = ConstNode::new(0.0);
+        let vertical = ConstNode::new(0.5);
+
+        let seed = Seed::DEFAULT_SEED;
+        let gabor_h: GaborNode<Wyhash, _> = GaborNode::new(&seed, &horizontal, 2.0, 0.5, 8);
+        let gabor_v: GaborNode<Wyhash, _> = GaborNode::new(&seed, &vertical, 2.0, 0.5, 8);
+
+        let x = RealPoint::new([1.0, 0.0]);
+        let y = RealPoint::new([0.0, 1.0]);
+
+        assert!(directional_variance(&gabor_h, x) > directional_variance(&gabor_h, y) * 4.0);
+        assert!(directional_variance(&gabor_v, y) > directional_variance(&gabor_v, x) * 4.0);
+    }
+
+    #[test]
+    fn normalized() {
+        let orientation = ConstNode::new(0.25);
+        let gabor: GaborNode<Wyhash, _> = GaborNode::new(&Seed::DEFAULT_SEED, &orientation, 1.5, 0.75, 6);
+
+        let region = SampleRegion::new(RealPoint::new([0.0, 0.0]), 0.43, [32, 32]);
+        let samples: Vec<f64> = region.points().map(|point| gabor.raw_value_at(point)).collect();
+        let summary = Summary::from_samples(&samples);
+
+        assert!(summary.mean.abs() < 0.2, "{:?}", summary);
+        assert!(summary.variance > 0.5 && summary.variance < 1.5, "{:?}", summary);
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{geometry::{HexLayout, RealPoint}, noise::NoiseNode, random::{HashFn, Seed}, utils};
+
+// Constant random value across each hex of the layout
+pub struct HexTileNode<T> where T: HashFn {
+    hash_fn: T,
+    layout: HexLayout,
+}
+
+impl<T> HexTileNode<T> where T: HashFn {
+    pub fn new(seed: &Seed, layout: HexLayout) -> Self {
+        Self { hash_fn: T::from_seed(seed), layout }
+    }
+}
+
+impl<T> NoiseNode<2> for HexTileNode<T> where T: HashFn {
+    fn value_at(&self, point: RealPoint<2>) -> f64 {
+        let hex = self.layout.hex_at(point);
+        let hash = self.hash_fn.hash_bytes(hex.to_axial_point().as_bytes());
+
+        utils::f64_from_mantissa(hash, 0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{Hex, HexLayout, HexOrientation, RealPoint},
+        noise::{HexTileNode, NoiseNode},
+        random::{Seed, Wyhash},
+    };
+
+    #[test]
+    fn constant_per_hex() {
+        let layout = HexLayout::new(HexOrientation::Flat, 3.0);
+        let node: HexTileNode<Wyhash> = HexTileNode::new(&Seed::DEFAULT_SEED, layout);
+
+        let center = layout.center(Hex::new(2, -1));
+        let value = node.value_at(center);
+
+        assert!((0.0..1.0).contains(&value));
+        assert_eq!(node.value_at(center + RealPoint::new([1.2, -1.2])), value);
+        assert_ne!(node.value_at(layout.center(Hex::new(3, -1))), value);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{
+    geometry::{EuclideanMetric, Hex, HexLayout, RealPoint},
+    noise::{impulse_scatter::ImpulseScatter, NoiseNode, WorleyPaintMethod},
+    random::{HashFn, Seed},
+    utils,
+};
+
+// Worley noise with one feature point per hex of the layout. Feature points are jittered by up to
+// a quarter hex along each axial direction, so the nearest two always lie within two rings.
+pub struct HexWorleyNode<T> where T: HashFn {
+    scatter: ImpulseScatter<2, T>,
+    layout: HexLayout,
+    paint_method: WorleyPaintMethod,
+}
+
+impl<T> HexWorleyNode<T> where T: HashFn {
+    pub fn new(seed: &Seed, layout: HexLayout, paint_method: WorleyPaintMethod) -> Self {
+        Self { scatter: ImpulseScatter::new(seed), layout, paint_method }
+    }
+
+    fn hex_seed_point(&self, hex: Hex) -> (RealPoint<2>, u64) {
+        let axial = hex.to_axial_point();
+        let (feature_point, hash) = self.scatter.feature_point(axial);
+
+        // Shrink the unit offset within the axial cell onto [-0.25, 0.25) along each axis
+        let offset = (feature_point - axial.to_real_point() - 0.5) * 0.5;
+
+        let center = self.layout.center(hex);
+        let q_axis = self.layout.center(Hex::new(1, 0)) - self.layout.center(Hex::new(0, 0));
+        let r_axis = self.layout.center(Hex::new(0, 1)) - self.layout.center(Hex::new(0, 0));
+
+        (center + q_axis * offset[0] + r_axis * offset[1], hash)
+    }
+}
+
+impl<T> NoiseNode<2> for HexWorleyNode<T> where T: HashFn {
+    fn value_at(&self, point: RealPoint<2>) -> f64 {
+        let mut candidates = self.layout.hex_at(point).spiral(2).map(|hex| {
+            let (seed_point, seed_value) = self.hex_seed_point(hex);
+            let distance = (seed_point - point).magnitude::<EuclideanMetric>();
+
+            (seed_value, distance)
+        }).collect::<Vec<(u64, f64)>>();
+
+        candidates.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+
+        let (seed_value, distance) = candidates[0];
+
+        match self.paint_method {
+            WorleyPaintMethod::Value => {
+                utils::f64_from_mantissa(seed_value, 0.0, 1.0)
+            },
+
+            WorleyPaintMethod::Distance => {
+                let (_, other_distance) = candidates[1];
+
+                // Spacing between adjacent hex centres
+                let max_distance = self.layout.size * 3.0f64.sqrt();
+
+                (other_distance - distance) / max_distance
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{Hex, HexLayout, HexOrientation, RealPoint},
+        noise::{HexWorleyNode, NoiseNode, WorleyPaintMethod},
+        random::{Seed, Wyhash},
+    };
+
+    #[test]
+    fn hex_worley() {
+        let layout = HexLayout::new(HexOrientation::Pointy, 1.0);
+        let seed = Seed::DEFAULT_SEED;
+
+        let values: HexWorleyNode<Wyhash> = HexWorleyNode::new(&seed, layout, WorleyPaintMethod::Value);
+        let distances: HexWorleyNode<Wyhash> = HexWorleyNode::new(&seed, layout, WorleyPaintMethod::Distance);
+
+        // Feature points stay close enough to their centres that each centre picks its own hex
+        for hex in Hex::new(0, 0).spiral(4) {
+            let center = layout.center(hex);
+            let expected = values.value_at(center);
+
+            assert!((0.0..1.0).contains(&expected));
+            assert!(distances.value_at(center) > 0.0);
+
+            let nearby = values.value_at(center + RealPoint::new([0.05, 0.05]));
+
+            assert_eq!(nearby, expected);
+        };
+    }
+}
@@ -0,0 +1,73 @@
+use std::cell::{RefCell, RefMut};
+use rand::RngCore;
+use rand_chacha::ChaCha8Rng;
+use crate::{geometry::{Point, RealPoint}, random::{HashFn, Seed, Seedable}, utils};
+
+// Deterministically scatters impulses and feature points within lattice cells: each cell's hash
+// selects a ChaCha stream, so a cell always produces the same impulses regardless of the order in
+// which cells are visited
+pub(crate) struct ImpulseScatter<const DIM: usize, T> where T: HashFn {
+    hash_fn: T,
+    rng: RefCell<ChaCha8Rng>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub(crate) struct Impulse<const DIM: usize> {
+    pub position: RealPoint<DIM>,
+    pub weight: f64,
+    pub phase: f64,
+}
+
+impl<const DIM: usize, T> ImpulseScatter<DIM, T> where T: HashFn {
+    pub fn new(seed: &Seed) -> Self {
+        Self {
+            hash_fn: T::from_seed(seed),
+            rng: RefCell::new(ChaCha8Rng::from_seed(seed)),
+        }
+    }
+
+    // Rewinds the generator onto the stream selected by the cell's hash, which is also returned
+    fn cell_rng(&self, cell: Point<DIM>) -> (RefMut<'_, ChaCha8Rng>, u64) {
+        let hash = self.hash_fn.hash_bytes(cell.as_bytes());
+
+        let mut rng = self.rng.borrow_mut();
+
+        rng.set_word_pos(0);
+        rng.set_stream(hash);
+
+        (rng, hash)
+    }
+
+    // A single point placed uniformly within the cell, alongside the cell's hash. This is the
+    // position of the cell's first impulse.
+    pub fn feature_point(&self, cell: Point<DIM>) -> (RealPoint<DIM>, u64) {
+        let (mut rng, hash) = self.cell_rng(cell);
+
+        (random_point(cell, &mut rng), hash)
+    }
+
+    // Impulses lie within the cell in lattice units; weights fall in [-1.0, 1.0] and phases in
+    // [0.0, 1.0]
+    pub fn impulses(&self, cell: Point<DIM>, num_impulses: usize) -> Vec<Impulse<DIM>> {
+        let (mut rng, _) = self.cell_rng(cell);
+
+        (0..num_impulses).map(|_| {
+            Impulse {
+                position: random_point(cell, &mut rng),
+                weight: utils::f64_from_mantissa(rng.next_u64(), -1.0, 1.0),
+                phase: utils::f64_from_mantissa(rng.next_u64(), 0.0, 1.0),
+            }
+        }).collect()
+    }
+}
+
+fn random_point<const DIM: usize>(cell: Point<DIM>, rng: &mut ChaCha8Rng) -> RealPoint<DIM> {
+    let real_cell = cell.to_real_point();
+    let mut coordinates = [0.0f64; DIM];
+
+    for (dim, coordinate) in coordinates.iter_mut().enumerate() {
+        *coordinate = real_cell[dim] + utils::f64_from_mantissa(rng.next_u64(), 0.0, 1.0);
+    };
+
+    RealPoint::new(coordinates)
+}
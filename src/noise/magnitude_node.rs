@@ -0,0 +1,22 @@
+use std::marker::PhantomData;
+use crate::{geometry::{DistanceMetric, RealPoint}, noise::{NoiseNode, VectorNoiseNode}};
+
+pub struct MagnitudeNode<'a, const IN: usize, const OUT: usize, T, U>
+where T: VectorNoiseNode<IN, OUT>, U: DistanceMetric {
+    source: &'a T,
+    phantom: PhantomData<U>,
+}
+
+impl<'a, const IN: usize, const OUT: usize, T, U> MagnitudeNode<'a, IN, OUT, T, U>
+where T: VectorNoiseNode<IN, OUT>, U: DistanceMetric {
+    pub fn new(source: &'a T) -> Self {
+        Self { source, phantom: PhantomData }
+    }
+}
+
+impl<'a, const IN: usize, const OUT: usize, T, U> NoiseNode<IN> for MagnitudeNode<'a, IN, OUT, T, U>
+where T: VectorNoiseNode<IN, OUT>, U: DistanceMetric {
+    fn value_at(&self, point: RealPoint<IN>) -> f64 {
+        self.source.vector_at(point).magnitude::<U>()
+    }
+}
@@ -1,31 +1,58 @@
+mod color_node;
+mod component_node;
 mod const_node;
+mod curl_node;
+mod dot_node;
 mod function;
+mod gabor_node;
 mod gradient;
 mod harmonic_node;
+mod hex_tile_node;
+mod hex_worley_node;
 mod hypersphere_node;
+mod impulse_scatter;
 mod invert_node;
 mod lerp_node;
+mod magnitude_node;
 mod noise_node;
 mod overlay_node;
 mod perlin_node;
+mod phasor_node;
 mod screen_node;
 mod sigmoid_node;
 mod soft_light_node;
+mod stack_node;
 mod tile_node;
+mod vector_noise_node;
+mod warp_node;
+mod wavelet_node;
 mod worley_node;
 
+pub use color_node::ColorNode;
+pub use component_node::ComponentNode;
 pub use const_node::ConstNode;
+pub use curl_node::{CurlNode2, CurlNode3};
+pub use dot_node::DotNode;
 pub use function::{perlin_1d, perlin_2d, perlin_3d};
+pub use gabor_node::GaborNode;
 pub use gradient::{GRADIENTS_2D, GRADIENTS_3D, gen_gradients};
 pub use harmonic_node::HarmonicNode;
+pub use hex_tile_node::HexTileNode;
+pub use hex_worley_node::HexWorleyNode;
 pub use hypersphere_node::HypersphereNode;
 pub use invert_node::InvertNode;
 pub use lerp_node::LerpNode;
+pub use magnitude_node::MagnitudeNode;
 pub use noise_node::NoiseNode;
 pub use overlay_node::OverlayNode;
 pub use perlin_node::PerlinNode;
+pub use phasor_node::{PhasorNode, PhasorProfile};
 pub use screen_node::ScreenNode;
 pub use sigmoid_node::SigmoidNode;
 pub use soft_light_node::SoftLightNode;
+pub use stack_node::StackNode;
 pub use tile_node::TileNode;
+pub use vector_noise_node::VectorNoiseNode;
+pub use warp_node::WarpNode;
+pub use wavelet_node::{WaveletHarmonicNode, WaveletNode};
 pub use worley_node::{WorleyNode, WorleyPaintMethod};
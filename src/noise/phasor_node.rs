@@ -0,0 +1,155 @@
+use std::f64::consts::{PI, TAU};
+use crate::{
+    geometry::RealPoint,
+    noise::{impulse_scatter::ImpulseScatter, NoiseNode},
+    random::{HashFn, Seed},
+    utils,
+};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum PhasorProfile {
+    // Raw phase angle, in the range [-PI, PI]
+    Phase,
+    Sine,
+    Sawtooth,
+}
+
+// Phasor noise (Tricard et al. 2019) takes the phase of a complex Gabor kernel sum rather than its
+// real part, which yields oscillations of uniform contrast regardless of how kernels overlap.
+// Orientation maps [0.0, 1.0] onto angles in [0, PI), frequency is used directly in cycles per unit;
+// both are evaluated at each kernel's position.
+pub struct PhasorNode<'a, T, U, V> where T: HashFn, U: NoiseNode<2>, V: NoiseNode<2> {
+    scatter: ImpulseScatter<2, T>,
+    orientation: &'a U,
+    frequency: &'a V,
+    bandwidth: f64,
+    impulses_per_cell: usize,
+    profile: PhasorProfile,
+}
+
+impl<'a, T, U, V> PhasorNode<'a, T, U, V> where T: HashFn, U: NoiseNode<2>, V: NoiseNode<2> {
+    pub fn new(
+        seed: &Seed,
+        orientation: &'a U,
+        frequency: &'a V,
+        bandwidth: f64,
+        impulses_per_cell: usize,
+        profile: PhasorProfile,
+    ) -> Self {
+        Self {
+            scatter: ImpulseScatter::new(seed),
+            orientation,
+            frequency,
+            bandwidth,
+            impulses_per_cell,
+            profile,
+        }
+    }
+
+    pub fn phase_at(&self, point: RealPoint<2>) -> f64 {
+        let radius = 1.0 / self.bandwidth;
+        let cell_point = point / radius;
+
+        let mut re = 0.0f64;
+        let mut im = 0.0f64;
+
+        for cell in cell_point.to_lattice_point().neighbors_and_self() {
+            for impulse in self.scatter.impulses(cell, self.impulses_per_cell) {
+                let position = impulse.position * radius;
+                let offset = point - position;
+
+                if offset.dot_prod(offset) >= radius * radius {
+                    continue;
+                };
+
+                let angle = self.orientation.value_at(position) * PI;
+                let frequency = self.frequency.value_at(position);
+
+                let (sin, cos) = angle.sin_cos();
+                let envelope = (-PI * self.bandwidth * self.bandwidth * offset.dot_prod(offset)).exp();
+                let phase = TAU * (frequency * (offset[0] * cos + offset[1] * sin) + impulse.phase);
+
+                re += impulse.weight * envelope * phase.cos();
+                im += impulse.weight * envelope * phase.sin();
+            };
+        };
+
+        im.atan2(re)
+    }
+}
+
+impl<'a, T, U, V> NoiseNode<2> for PhasorNode<'a, T, U, V>
+where T: HashFn, U: NoiseNode<2>, V: NoiseNode<2> {
+    fn value_at(&self, point: RealPoint<2>) -> f64 {
+        let phase = self.phase_at(point);
+
+        match self.profile {
+            PhasorProfile::Phase => phase,
+            PhasorProfile::Sine => utils::neg_unit_to_unit(phase.sin()),
+            PhasorProfile::Sawtooth => sawtooth(phase),
+        }
+    }
+}
+
+// Maps a phase onto [0.0, 1.0); `rem_euclid` rounds up to exactly TAU for tiny negative phases
+fn sawtooth(phase: f64) -> f64 {
+    (phase.rem_euclid(TAU) / TAU).min(1.0 - f64::EPSILON)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+    use crate::{
+        analysis::SampleRegion,
+        geometry::RealPoint,
+        noise::{phasor_node::sawtooth, ConstNode, NoiseNode, PhasorNode, PhasorProfile},
+        random::{Seed, Wyhash},
+    };
+
+    #[test]
+    fn profiles() {
+        let orientation = ConstNode::new(0.3);
+        let frequency = ConstNode::new(3.0);
+        let seed = Seed::DEFAULT_SEED;
+
+        let phase: PhasorNode<Wyhash, _, _> = PhasorNode::new(
+            &seed, &orientation, &frequency, 0.5, 4, PhasorProfile::Phase,
+        );
+
+        let sine: PhasorNode<Wyhash, _, _> = PhasorNode::new(
+            &seed, &orientation, &frequency, 0.5, 4, PhasorProfile::Sine,
+        );
+
+        let sawtooth: PhasorNode<Wyhash, _, _> = PhasorNode::new(
+            &seed, &orientation, &frequency, 0.5, 4, PhasorProfile::Sawtooth,
+        );
+
+        let region = SampleRegion::new(RealPoint::new([0.2, 0.2]), 0.29, [24, 24]);
+        let mut contrast = 0.0f64;
+
+        for point in region.points() {
+            let angle = phase.value_at(point);
+
+            assert!((-PI..=PI).contains(&angle));
+            assert!((0.0..=1.0).contains(&sine.value_at(point)));
+            assert!((0.0..1.0).contains(&sawtooth.value_at(point)));
+            assert!((sine.value_at(point) - 0.5 - angle.sin() / 2.0).abs() < 1e-12);
+
+            contrast += (sine.value_at(point) * 2.0 - 1.0).abs();
+        };
+
+        // A sinusoid of uniformly distributed phase has a mean absolute value of 2/PI; Gabor noise
+        // would fall well short of this as kernels interfere
+        let mean_contrast = contrast / (region.num_samples() as f64);
+
+        assert!(mean_contrast > 0.55, "{}", mean_contrast);
+    }
+
+    #[test]
+    fn sawtooth_range() {
+        assert_eq!(sawtooth(0.0), 0.0);
+        assert_eq!(sawtooth(PI), 0.5);
+        assert!((0.0..1.0).contains(&sawtooth(-1e-20)));
+        assert!((0.0..1.0).contains(&sawtooth(-f64::MIN_POSITIVE)));
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{geometry::RealPoint, noise::{NoiseNode, VectorNoiseNode}};
+
+// Combines OUT scalar nodes into a single vector node, one node per component
+pub struct StackNode<'a, const IN: usize, const OUT: usize> {
+    sources: [&'a dyn NoiseNode<IN>; OUT],
+}
+
+impl<'a, const IN: usize, const OUT: usize> StackNode<'a, IN, OUT> {
+    pub fn new(sources: [&'a dyn NoiseNode<IN>; OUT]) -> Self {
+        Self { sources }
+    }
+}
+
+impl<'a, const IN: usize, const OUT: usize> VectorNoiseNode<IN, OUT> for StackNode<'a, IN, OUT> {
+    fn vector_at(&self, point: RealPoint<IN>) -> RealPoint<OUT> {
+        RealPoint::new(self.sources.map(|source| source.value_at(point)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{ChebyshevMetric, EuclideanMetric, RealPoint},
+        noise::{ComponentNode, ConstNode, DotNode, MagnitudeNode, NoiseNode, StackNode, WarpNode},
+    };
+
+    struct XNode;
+
+    impl NoiseNode<2> for XNode {
+        fn value_at(&self, point: RealPoint<2>) -> f64 {
+            point[0]
+        }
+    }
+
+    #[test]
+    fn adapters() {
+        let x = ConstNode::new(3.0);
+        let y = ConstNode::new(-4.0);
+        let stack: StackNode<2, 2> = StackNode::new([&x, &y]);
+        let point = RealPoint::new([0.5, 0.5]);
+
+        assert_eq!(ComponentNode::new(&stack, 1).value_at(point), -4.0);
+        assert_eq!(MagnitudeNode::<2, 2, _, EuclideanMetric>::new(&stack).value_at(point), 5.0);
+        assert_eq!(MagnitudeNode::<2, 2, _, ChebyshevMetric>::new(&stack).value_at(point), 4.0);
+        assert_eq!(DotNode::new(&stack, RealPoint::new([1.0, 1.0])).value_at(point), -1.0);
+        assert_eq!(WarpNode::new(&XNode, &stack, 0.5).value_at(point), 2.0);
+    }
+}
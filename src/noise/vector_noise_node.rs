@@ -0,0 +1,5 @@
+use crate::geometry::RealPoint;
+
+pub trait VectorNoiseNode<const IN: usize, const OUT: usize> {
+    fn vector_at(&self, point: RealPoint<IN>) -> RealPoint<OUT>;
+}
@@ -0,0 +1,22 @@
+use crate::{geometry::RealPoint, noise::{NoiseNode, VectorNoiseNode}};
+
+// Samples `source` at a point displaced by the output of `offset`, scaled by `amplitude`
+pub struct WarpNode<'a, const DIM: usize, T, U> where T: NoiseNode<DIM>, U: VectorNoiseNode<DIM, DIM> {
+    source: &'a T,
+    offset: &'a U,
+    amplitude: f64,
+}
+
+impl<'a, const DIM: usize, T, U> WarpNode<'a, DIM, T, U>
+where T: NoiseNode<DIM>, U: VectorNoiseNode<DIM, DIM> {
+    pub fn new(source: &'a T, offset: &'a U, amplitude: f64) -> Self {
+        Self { source, offset, amplitude }
+    }
+}
+
+impl<'a, const DIM: usize, T, U> NoiseNode<DIM> for WarpNode<'a, DIM, T, U>
+where T: NoiseNode<DIM>, U: VectorNoiseNode<DIM, DIM> {
+    fn value_at(&self, point: RealPoint<DIM>) -> f64 {
+        self.source.value_at(point + self.offset.vector_at(point) * self.amplitude)
+    }
+}
@@ -0,0 +1,295 @@
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use rand_distr::StandardNormal;
+use crate::{geometry::{Point, RealPoint}, noise::NoiseNode, random::{Seed, Seedable}, utils};
+
+// Analysis filter from Cook & DeRose, "Wavelet Noise" (2005)
+const DOWNSAMPLE_RADIUS: isize = 16;
+const DOWNSAMPLE_COEFFICIENTS: [f64; 32] = [
+     0.000334, -0.001528,  0.000410,  0.003545, -0.000938, -0.008233,  0.002172,  0.019120,
+    -0.005040, -0.044412,  0.011655,  0.103311, -0.025936, -0.243780,  0.033979,  0.655340,
+     0.655340,  0.033979, -0.243780, -0.025936,  0.103311,  0.011655, -0.044412, -0.005040,
+     0.019120,  0.002172, -0.008233, -0.000938,  0.003546,  0.000410, -0.001528,  0.000334,
+];
+
+// Quadratic B-spline refinement filter
+const UPSAMPLE_COEFFICIENTS: [f64; 4] = [0.25, 0.75, 0.75, 0.25];
+
+const NUM_VARIANCE_SAMPLES: usize = 1024;
+
+// Band-limited noise built from a periodic tile of random coefficients with their coarse-scale
+// components removed, reconstructed with a quadratic B-spline. Energy is concentrated in a single
+// octave, so summed octaves neither leak into each other nor alias when sampled coarsely.
+pub struct WaveletNode<const DIM: usize> {
+    tile: Vec<f64>,
+    tile_size: usize,
+    std_dev: f64,
+}
+
+impl<const DIM: usize> WaveletNode<DIM> {
+    // `tile_size` must be even; 128 is a reasonable choice in 2D and 32 in 3D
+    pub fn new(seed: &Seed, tile_size: usize) -> Self {
+        assert!(tile_size >= 2 && tile_size.is_multiple_of(2), "Wavelet tile size must be even");
+
+        let mut rng = ChaCha8Rng::from_seed(seed);
+        let num_coefficients = tile_size.pow(DIM as u32);
+
+        let mut tile: Vec<f64> = (0..num_coefficients).map(|_| rng.sample(StandardNormal)).collect();
+        let mut coarse = tile.clone();
+        let mut half = vec![0.0f64; num_coefficients];
+
+        for dim in 0..DIM {
+            let stride = tile_size.pow(dim as u32);
+
+            for start in (0..num_coefficients).filter(|idx| (idx / stride).is_multiple_of(tile_size)) {
+                downsample(&coarse, &mut half, start, stride, tile_size);
+                upsample(&half, &mut coarse, start, stride, tile_size);
+            };
+        };
+
+        for (coefficient, coarse_coefficient) in tile.iter_mut().zip(coarse.iter()) {
+            *coefficient -= coarse_coefficient;
+        };
+
+        // Adding a copy of the tile shifted by an odd offset evens out the variance between even and
+        // odd lattice points
+        let mut offset = tile_size / 2;
+
+        if offset.is_multiple_of(2) {
+            offset += 1;
+        };
+
+        let shifted: Vec<f64> = (0..num_coefficients).map(|idx| {
+            let mut shifted_idx = 0usize;
+            let mut remainder = idx;
+
+            for dim in 0..DIM {
+                let coordinate = (remainder % tile_size + offset) % tile_size;
+
+                shifted_idx += coordinate * tile_size.pow(dim as u32);
+                remainder /= tile_size;
+            };
+
+            tile[shifted_idx]
+        }).collect();
+
+        for (coefficient, shifted_coefficient) in tile.iter_mut().zip(shifted.iter()) {
+            *coefficient += shifted_coefficient;
+        };
+
+        let mut node = Self { tile, tile_size, std_dev: 1.0 };
+
+        let variance = (0..NUM_VARIANCE_SAMPLES).map(|_| {
+            let mut coordinates = [0.0f64; DIM];
+
+            for coordinate in coordinates.iter_mut() {
+                *coordinate = rng.gen_range(0.0..(tile_size as f64));
+            };
+
+            node.raw_value_at(RealPoint::new(coordinates)).powi(2)
+        }).sum::<f64>() / (NUM_VARIANCE_SAMPLES as f64);
+
+        node.std_dev = variance.sqrt();
+        node
+    }
+
+    pub fn tile_size(&self) -> usize {
+        self.tile_size
+    }
+
+    // Zero-mean noise with unit variance, periodic over `tile_size`
+    pub fn raw_value_at(&self, point: RealPoint<DIM>) -> f64 {
+        let mut weights = [[0.0f64; 3]; DIM];
+        let mut centre = [0i32; DIM];
+
+        for dim in 0..DIM {
+            let mid = (point[dim] - 0.5).ceil();
+            let t = mid - (point[dim] - 0.5);
+
+            weights[dim][0] = t * t / 2.0;
+            weights[dim][2] = (1.0 - t) * (1.0 - t) / 2.0;
+            weights[dim][1] = 1.0 - weights[dim][0] - weights[dim][2];
+
+            centre[dim] = mid as i32;
+        };
+
+        let centre = Point::new(centre);
+        let tile_size = self.tile_size as i32;
+
+        let value: f64 = centre.neighbors_and_self().map(|vertex| {
+            let mut weight = 1.0f64;
+            let mut idx = 0usize;
+
+            for dim in 0..DIM {
+                let offset = (vertex[dim] - centre[dim] + 1) as usize;
+                let coordinate = vertex[dim].rem_euclid(tile_size) as usize;
+
+                weight *= weights[dim][offset];
+                idx += coordinate * self.tile_size.pow(dim as u32);
+            };
+
+            weight * self.tile[idx]
+        }).sum();
+
+        value / self.std_dev
+    }
+}
+
+impl<const DIM: usize> NoiseNode<DIM> for WaveletNode<DIM> {
+    fn value_at(&self, point: RealPoint<DIM>) -> f64 {
+        to_unit(self.raw_value_at(point))
+    }
+}
+
+// Maps unit-variance noise onto [0.0, 1.0], saturating beyond three standard deviations
+fn to_unit(value: f64) -> f64 {
+    utils::neg_unit_to_unit((value / 3.0).clamp(-1.0, 1.0))
+}
+
+fn downsample(from: &[f64], to: &mut [f64], start: usize, stride: usize, length: usize) {
+    let length = length as isize;
+
+    for i in 0..(length / 2) {
+        let mut value = 0.0f64;
+
+        for k in (2 * i - DOWNSAMPLE_RADIUS)..(2 * i + DOWNSAMPLE_RADIUS) {
+            let coefficient = DOWNSAMPLE_COEFFICIENTS[(k - 2 * i + DOWNSAMPLE_RADIUS) as usize];
+
+            value += coefficient * from[start + (k.rem_euclid(length) as usize) * stride];
+        };
+
+        to[start + (i as usize) * stride] = value;
+    };
+}
+
+fn upsample(from: &[f64], to: &mut [f64], start: usize, stride: usize, length: usize) {
+    let length = length as isize;
+
+    for i in 0..length {
+        let mut value = 0.0f64;
+
+        for k in (i / 2)..=(i / 2 + 1) {
+            let coefficient = UPSAMPLE_COEFFICIENTS[(i - 2 * k + 2) as usize];
+
+            value += coefficient * from[start + (k.rem_euclid(length / 2) as usize) * stride];
+        };
+
+        to[start + (i as usize) * stride] = value;
+    };
+}
+
+// Fractal sum of wavelet noise octaves at doubling frequencies. Octaves whose frequency exceeds the
+// Nyquist limit for the given sample footprint are faded out, so a map rendered with one sample
+// every `footprint` units stays free of aliasing at any zoom level.
+pub struct WaveletHarmonicNode<'a, const DIM: usize> {
+    source: &'a WaveletNode<DIM>,
+    num_octaves: usize,
+    persistence: f64,
+    footprint: f64,
+}
+
+impl<'a, const DIM: usize> WaveletHarmonicNode<'a, DIM> {
+    pub fn new(source: &'a WaveletNode<DIM>, num_octaves: usize, persistence: f64) -> Self {
+        Self { source, num_octaves, persistence, footprint: 0.0 }
+    }
+
+    // Distance between neighbouring samples, e.g. the size of one pixel in noise space
+    pub fn with_footprint(self, footprint: f64) -> Self {
+        Self { footprint, ..self }
+    }
+
+    pub fn raw_value_at(&self, point: RealPoint<DIM>) -> f64 {
+        let mut value: f64 = 0.0;
+
+        let mut variance: f64 = 0.0;
+        let mut frequency: f64 = 1.0;
+        let mut amplitude: f64 = 1.0;
+
+        for _ in 0..self.num_octaves {
+            // Each octave carries its energy between a quarter and a half of its frequency, so it
+            // is fully resolved while the upper edge of the band sits below the Nyquist limit, and
+            // unresolvable once the lower edge passes it
+            let attenuation = (2.0 - frequency * self.footprint).clamp(0.0, 1.0);
+
+            if attenuation > 0.0 {
+                value += self.source.raw_value_at(point * frequency) * amplitude * attenuation;
+            };
+
+            variance += amplitude * amplitude;
+            amplitude *= self.persistence;
+            frequency *= 2.0;
+        };
+
+        value / variance.sqrt()
+    }
+}
+
+impl<'a, const DIM: usize> NoiseNode<DIM> for WaveletHarmonicNode<'a, DIM> {
+    fn value_at(&self, point: RealPoint<DIM>) -> f64 {
+        to_unit(self.raw_value_at(point))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        analysis::{RadialSpectrum, SampleRegion, Summary},
+        geometry::RealPoint,
+        noise::{WaveletHarmonicNode, WaveletNode},
+        random::Seed,
+    };
+
+    #[test]
+    fn deterministic_and_periodic() {
+        let lhs: WaveletNode<2> = WaveletNode::new(&Seed::DEFAULT_SEED, 32);
+        let rhs: WaveletNode<2> = WaveletNode::new(&Seed::DEFAULT_SEED, 32);
+        let point = RealPoint::new([3.7, 12.1]);
+
+        assert_eq!(lhs.raw_value_at(point), rhs.raw_value_at(point));
+
+        let wrapped = point + RealPoint::new([32.0, -64.0]);
+
+        assert!((lhs.raw_value_at(point) - lhs.raw_value_at(wrapped)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn unit_variance() {
+        let wavelet: WaveletNode<2> = WaveletNode::new(&Seed::DEFAULT_SEED, 64);
+        let region = SampleRegion::new(RealPoint::new([0.1, 0.2]), 0.37, [64, 64]);
+
+        let samples: Vec<f64> = region.points().map(|point| wavelet.raw_value_at(point)).collect();
+        let summary = Summary::from_samples(&samples);
+
+        assert!(summary.mean.abs() < 0.1, "{:?}", summary);
+        assert!((summary.variance - 1.0).abs() < 0.2, "{:?}", summary);
+    }
+
+    #[test]
+    fn band_limited() {
+        let wavelet: WaveletNode<2> = WaveletNode::new(&Seed::DEFAULT_SEED, 64);
+        let region = SampleRegion::new(RealPoint::new([0.0, 0.0]), 0.25, [64, 64]);
+        let spectrum = RadialSpectrum::measure(&wavelet, &region);
+
+        // The region spans 16 units, so the band between a quarter and a half cycle per unit sits
+        // between bins 4 and 8; very little energy should fall at the lowest frequencies
+        let power = spectrum.power();
+        let low: f64 = power[1..3].iter().sum();
+        let band: f64 = power[4..9].iter().sum();
+
+        assert!(low * 10.0 < band, "{} vs {}", low, band);
+    }
+
+    #[test]
+    fn footprint_drops_fine_octaves() {
+        let wavelet: WaveletNode<2> = WaveletNode::new(&Seed::DEFAULT_SEED, 32);
+        let fine = WaveletHarmonicNode::new(&wavelet, 6, 0.5);
+        let coarse = WaveletHarmonicNode::new(&wavelet, 6, 0.5).with_footprint(1.0);
+        let base = WaveletHarmonicNode::new(&wavelet, 1, 0.5);
+
+        let point = RealPoint::new([5.3, 1.9]);
+        let normalization = (1.0f64 + 0.25 + 0.0625 + 0.015625 + 0.00390625 + 0.0009765625).sqrt();
+
+        assert_ne!(fine.raw_value_at(point), coarse.raw_value_at(point));
+        assert!((coarse.raw_value_at(point) - base.raw_value_at(point) / normalization).abs() < 1e-12);
+    }
+}
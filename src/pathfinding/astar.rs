@@ -0,0 +1,192 @@
+use std::{collections::{HashMap, hash_map::Entry}, marker::PhantomData};
+use crate::{collections::PointQueue, geometry::{DistanceMetric, Neighborhood, Point}};
+
+// Best known cost to reach each point, and the point it was reached from
+type Visited<const DIM: usize> = HashMap<Point<DIM>, (f64, Option<Point<DIM>>)>;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Path<const DIM: usize> {
+    // From the start to the goal, inclusive
+    pub points: Vec<Point<DIM>>,
+    pub cost: f64,
+}
+
+// A* search over the lattice. Each point steps to those given by `neighborhood`, and `cost` gives
+// the price of that step, or `None` if it is impossible. The heuristic is the distance to the goal
+// under `T`, scaled by `heuristic_weight`; paths are optimal as long as no step costs less than the
+// heuristic distance it covers. Under von Neumann adjacency that holds for `ManhattanMetric` with
+// unit costs. Under Moore adjacency it holds for `ChebyshevMetric` with unit or Euclidean costs,
+// but `EuclideanMetric` needs Euclidean costs, since it rates a unit-cost diagonal step at √2.
+// Wider neighbourhoods need each longer step to cost at least its heuristic distance.
+pub struct PathFinder<const DIM: usize, T, N, F>
+where T: DistanceMetric, N: Neighborhood<DIM>, F: Fn(Point<DIM>, Point<DIM>) -> Option<f64> {
+    phantom: PhantomData<T>,
+    neighborhood: N,
+    cost: F,
+    heuristic_weight: f64,
+    max_expansions: Option<usize>,
+}
+
+impl<const DIM: usize, T, N, F> PathFinder<DIM, T, N, F>
+where T: DistanceMetric, N: Neighborhood<DIM>, F: Fn(Point<DIM>, Point<DIM>) -> Option<f64> {
+    pub fn new(neighborhood: N, cost: F) -> Self {
+        Self { phantom: PhantomData, neighborhood, cost, heuristic_weight: 1.0, max_expansions: None }
+    }
+
+    // Weights above one trade optimality for speed
+    pub fn with_heuristic_weight(mut self, heuristic_weight: f64) -> Self {
+        self.heuristic_weight = heuristic_weight;
+        self
+    }
+
+    // Gives up after expanding this many points; needed on unbounded lattices where the goal may be
+    // unreachable
+    pub fn with_max_expansions(mut self, max_expansions: usize) -> Self {
+        self.max_expansions = Some(max_expansions);
+        self
+    }
+
+    pub fn find_path(&self, start: Point<DIM>, goal: Point<DIM>) -> Option<Path<DIM>> {
+        let heuristic = |point: Point<DIM>| {
+            (goal - point).to_real_point().magnitude::<T>() * self.heuristic_weight
+        };
+
+        let mut visited: Visited<DIM> = HashMap::new();
+        let mut queue = PointQueue::new();
+        let mut num_expansions = 0;
+
+        visited.insert(start, (0.0, None));
+        queue.push(start, heuristic(start));
+
+        while let Some((point, priority)) = queue.pop() {
+            let cost = visited[&point].0;
+
+            // Stale entry, superseded by a cheaper route
+            if priority > cost + heuristic(point) {
+                continue;
+            };
+
+            if point == goal {
+                return Some(Path { points: Self::reconstruct(&visited, goal), cost });
+            };
+
+            if self.max_expansions.is_some_and(|max_expansions| num_expansions >= max_expansions) {
+                return None;
+            };
+
+            num_expansions += 1;
+
+            for neighbor in self.neighborhood.neighbors(point) {
+                let Some(step_cost) = (self.cost)(point, neighbor) else {
+                    continue;
+                };
+
+                let neighbor_cost = cost + step_cost;
+
+                match visited.entry(neighbor) {
+                    Entry::Occupied(entry) if entry.get().0 <= neighbor_cost => continue,
+                    Entry::Occupied(mut entry) => { entry.insert((neighbor_cost, Some(point))); },
+                    Entry::Vacant(entry) => { entry.insert((neighbor_cost, Some(point))); },
+                };
+
+                queue.push(neighbor, neighbor_cost + heuristic(neighbor));
+            };
+        };
+
+        None
+    }
+
+    fn reconstruct(visited: &Visited<DIM>, goal: Point<DIM>) -> Vec<Point<DIM>> {
+        let mut points = vec![goal];
+
+        while let Some(previous) = visited[points.last().unwrap()].1 {
+            points.push(previous);
+        };
+
+        points.reverse();
+        points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{Adjacency, ChebyshevMetric, EuclideanMetric, LatticeNeighborhood, ManhattanMetric, Point},
+        pathfinding::PathFinder,
+    };
+
+    // A 10x10 room with a wall along x = 5, open only at y = 8
+    fn is_open(point: Point<2>) -> bool {
+        let inside = (0..10).contains(&point[0]) && (0..10).contains(&point[1]);
+
+        inside && (point[0] != 5 || point[1] == 8)
+    }
+
+    fn unit_cost(_: Point<2>, to: Point<2>) -> Option<f64> {
+        if is_open(to) { Some(1.0) } else { None }
+    }
+
+    #[test]
+    fn find_path() {
+        let start = Point::new([1, 1]);
+        let goal = Point::new([8, 1]);
+
+        let path = PathFinder::<2, ManhattanMetric, _, _>::new(Adjacency::VonNeumann, unit_cost)
+            .find_path(start, goal)
+            .unwrap();
+
+        assert_eq!(path.cost, 21.0);
+        assert_eq!(path.points.len(), 22);
+        assert!(path.points.contains(&Point::new([5, 8])));
+        assert!(path.points.windows(2).all(|pair| (pair[1] - pair[0]).abs().sum() == 1));
+
+        let path = PathFinder::<2, ChebyshevMetric, _, _>::new(Adjacency::Moore, unit_cost)
+            .find_path(start, goal)
+            .unwrap();
+
+        assert_eq!(path.cost, 14.0);
+
+        let euclidean_cost = |from: Point<2>, to: Point<2>| {
+            if is_open(to) { Some((to - from).to_real_point().magnitude::<EuclideanMetric>()) } else { None }
+        };
+
+        let path = PathFinder::<2, EuclideanMetric, _, _>::new(Adjacency::Moore, euclidean_cost)
+            .find_path(start, goal)
+            .unwrap();
+
+        assert!((path.cost - 7.0 * (1.0 + 2.0f64.sqrt())).abs() < 1e-9);
+    }
+
+    #[test]
+    fn unreachable() {
+        let blocked = |_: Point<3>, to: Point<3>| if to[0] == 3 { None } else { Some(1.0) };
+
+        let finder = PathFinder::<3, ManhattanMetric, _, _>::new(Adjacency::VonNeumann, blocked)
+            .with_max_expansions(1_000);
+
+        assert!(finder.find_path(Point::new([0, 0, 0]), Point::new([5, 0, 0])).is_none());
+        assert!(finder.find_path(Point::new([0, 0, 0]), Point::new([2, 2, 2])).is_some());
+    }
+
+    #[test]
+    fn wide_neighborhood() {
+        let euclidean_cost = |from: Point<2>, to: Point<2>| {
+            Some((to - from).to_real_point().magnitude::<EuclideanMetric>())
+        };
+
+        let neighborhood = |point| LatticeNeighborhood::within::<EuclideanMetric>(point, 2.3, false);
+
+        // Knight's moves are within reach, so the path runs straight along the (2, 1) diagonal
+        let path = PathFinder::<2, EuclideanMetric, _, _>::new(neighborhood, euclidean_cost)
+            .find_path(Point::new([0, 0]), Point::new([6, 3]))
+            .unwrap();
+
+        assert_eq!(path.points, vec![
+            Point::new([0, 0]),
+            Point::new([2, 1]),
+            Point::new([4, 2]),
+            Point::new([6, 3]),
+        ]);
+        assert!((path.cost - 45.0f64.sqrt()).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{collections::{Grid, PointQueue}, geometry::{Aabb, Adjacency, Neighborhood, Point}};
+
+// Cost of the cheapest route from any source to every point within the bounds. Following the
+// gradient downhill approaches the nearest source; following it uphill flees, although
+// `fleeing_map` gives better results for fleeing.
+#[derive(Clone, PartialEq, Debug)]
+pub struct DijkstraMap<const DIM: usize, N = Adjacency> where N: Neighborhood<DIM> {
+    bounds: Aabb<DIM>,
+    neighborhood: N,
+    distances: Grid<f64, DIM>,
+}
+
+impl<const DIM: usize, N> DijkstraMap<DIM, N> where N: Neighborhood<DIM> {
+    // Sources are given with their starting values, usually zero. Each point steps to those given by
+    // `neighborhood`, and `cost` gives the price of that step, or `None` if it is impossible.
+    pub fn new<F>(bounds: Aabb<DIM>, sources: &[(Point<DIM>, f64)], neighborhood: N, cost: F) -> Self
+    where F: Fn(Point<DIM>, Point<DIM>) -> Option<f64> {
+        let mut distances = Grid::new(bounds.size(), f64::INFINITY);
+        let mut queue = PointQueue::new();
+
+        for &(source, value) in sources {
+            if let Some(distance) = distances.get_mut(source - bounds.min) {
+                if value < *distance {
+                    *distance = value;
+                    queue.push(source, value);
+                };
+            };
+        };
+
+        while let Some((point, distance)) = queue.pop() {
+            if distance > distances[point - bounds.min] {
+                continue;
+            };
+
+            for neighbor in neighborhood.neighbors(point) {
+                let Some(neighbor_distance) = distances.get_mut(neighbor - bounds.min) else {
+                    continue;
+                };
+
+                let Some(step_cost) = cost(point, neighbor) else {
+                    continue;
+                };
+
+                if distance + step_cost < *neighbor_distance {
+                    *neighbor_distance = distance + step_cost;
+                    queue.push(neighbor, distance + step_cost);
+                };
+            };
+        };
+
+        Self { bounds, neighborhood, distances }
+    }
+
+    pub fn bounds(&self) -> Aabb<DIM> {
+        self.bounds
+    }
+
+    // `None` outside the bounds or where no source can be reached
+    pub fn distance(&self, point: Point<DIM>) -> Option<f64> {
+        self.distances.get(point - self.bounds.min).copied().filter(|distance| distance.is_finite())
+    }
+
+    pub fn is_reachable(&self, point: Point<DIM>) -> bool {
+        self.distance(point).is_some()
+    }
+
+    // Points within the bounds that no source can reach
+    pub fn unreachable(&self) -> impl Iterator<Item = Point<DIM>> + '_ {
+        self.distances.iter()
+            .filter(|(_, distance)| distance.is_infinite())
+            .map(|(point, _)| point + self.bounds.min)
+    }
+
+    // The adjacent point with the lowest value, if lower than that of `point`
+    pub fn descend(&self, point: Point<DIM>) -> Option<Point<DIM>> {
+        let mut best = (point, self.distance(point)?);
+
+        for neighbor in self.neighborhood.neighbors(point) {
+            if let Some(distance) = self.distance(neighbor) {
+                if distance < best.1 {
+                    best = (neighbor, distance);
+                };
+            };
+        };
+
+        if best.0 == point { None } else { Some(best.0) }
+    }
+
+    // The adjacent point with the highest value, if higher than that of `point`
+    pub fn ascend(&self, point: Point<DIM>) -> Option<Point<DIM>> {
+        let mut best = (point, self.distance(point)?);
+
+        for neighbor in self.neighborhood.neighbors(point) {
+            if let Some(distance) = self.distance(neighbor) {
+                if distance > best.1 {
+                    best = (neighbor, distance);
+                };
+            };
+        };
+
+        if best.0 == point { None } else { Some(best.0) }
+    }
+
+    // Rescans with every reachable point as a source valued at `-factor` times its distance.
+    // Descending the result flees from the original sources while preferring to run past them
+    // rather than into dead ends; factors around 1.2 work well.
+    pub fn fleeing_map<F>(&self, factor: f64, cost: F) -> Self
+    where N: Clone, F: Fn(Point<DIM>, Point<DIM>) -> Option<f64> {
+        let sources: Vec<(Point<DIM>, f64)> = self.distances.iter()
+            .filter(|(_, distance)| distance.is_finite())
+            .map(|(point, &distance)| (point + self.bounds.min, -factor * distance))
+            .collect();
+
+        Self::new(self.bounds, &sources, self.neighborhood.clone(), cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{geometry::{Aabb, Adjacency, Point}, pathfinding::DijkstraMap};
+
+    // Two rooms split by a wall along x = 4, with a separate closet at (8, 8)
+    fn is_open(point: Point<2>) -> bool {
+        point[0] != 4 && !(point[0] >= 7 && point[1] == 7) && !(point[0] == 7 && point[1] >= 7)
+    }
+
+    fn cost(_: Point<2>, to: Point<2>) -> Option<f64> {
+        if is_open(to) { Some(1.0) } else { None }
+    }
+
+    #[test]
+    fn dijkstra_map() {
+        let bounds = Aabb::with_size(Point::new([0, 0]), [10, 10]);
+        let sources = [(Point::new([0, 0]), 0.0), (Point::new([9, 0]), 0.0)];
+        let map = DijkstraMap::new(bounds, &sources, Adjacency::VonNeumann, cost);
+
+        assert_eq!(map.distance(Point::new([2, 3])), Some(5.0));
+        assert_eq!(map.distance(Point::new([6, 1])), Some(4.0));
+        assert_eq!(map.distance(Point::new([4, 0])), None);
+        assert_eq!(map.distance(Point::new([8, 8])), None);
+        assert_eq!(map.distance(Point::new([-1, 0])), None);
+
+        // Walls and the closet are unreachable
+        let unreachable: Vec<Point<2>> = map.unreachable().collect();
+
+        assert!(unreachable.contains(&Point::new([9, 9])));
+        assert!(unreachable.iter().all(|&point| !is_open(point) || point[0] > 7 && point[1] > 7));
+
+        // Rolling downhill reaches a source
+        let mut point = Point::new([3, 9]);
+
+        while let Some(next) = map.descend(point) {
+            point = next;
+        };
+
+        assert_eq!(point, Point::new([0, 0]));
+        assert!(map.ascend(Point::new([0, 0])).is_some());
+    }
+
+    #[test]
+    fn fleeing_map() {
+        let bounds = Aabb::with_size(Point::new([0, 0]), [4, 1]);
+        let map = DijkstraMap::new(bounds, &[(Point::new([1, 0]), 0.0)], Adjacency::Moore, cost);
+        let fleeing = map.fleeing_map(1.2, cost);
+
+        assert_eq!(fleeing.descend(Point::new([2, 0])), Some(Point::new([3, 0])));
+    }
+
+    #[test]
+    fn wide_neighborhood() {
+        let bounds = Aabb::with_size(Point::new([0, 0]), [10, 10]);
+        let neighborhood = |point: Point<2>| point.moore_neighbors(2);
+        let map = DijkstraMap::new(bounds, &[(Point::new([0, 0]), 0.0)], neighborhood, cost);
+
+        // Two-cell strides hop the wall along x = 4 and into the closet, but cannot land on walls
+        assert_eq!(map.distance(Point::new([8, 0])), Some(5.0));
+        assert_eq!(map.distance(Point::new([8, 8])), Some(5.0));
+        assert_eq!(map.distance(Point::new([4, 0])), None);
+    }
+}
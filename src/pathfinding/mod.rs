@@ -0,0 +1,5 @@
+mod astar;
+mod dijkstra_map;
+
+pub use astar::{Path, PathFinder};
+pub use dijkstra_map::DijkstraMap;
@@ -1,5 +1,7 @@
 use byteorder::{ByteOrder, LittleEndian};
 use rand::{Rng, rngs::OsRng};
+use rand_chacha::ChaCha8Rng;
+use crate::random::Seedable;
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 #[repr(C, align(8))]
@@ -33,6 +35,16 @@ impl Seed {
         Self::from_rng(&mut OsRng)
     }
 
+    // Seeds derived from the same parent using different streams are decorrelated from each other
+    // and from the parent
+    pub fn derive(&self, stream: u64) -> Self {
+        let mut rng = ChaCha8Rng::from_seed(self);
+
+        rng.set_stream(stream);
+
+        Self::from_rng(&mut rng)
+    }
+
     pub fn to_base58(&self) -> String {
         fn digit_to_char(digit: u64) -> char {
             let ord = match digit {
@@ -221,6 +233,16 @@ mod tests {
         assert_eq!(Seed::from_base58(MAX_STR), Ok(MAX_SEED));
     }
 
+    #[test]
+    fn derive() {
+        let lhs = Seed::DEFAULT_SEED.derive(0);
+        let rhs = Seed::DEFAULT_SEED.derive(1);
+
+        assert_eq!(lhs, Seed::DEFAULT_SEED.derive(0));
+        assert_ne!(lhs, rhs);
+        assert_ne!(lhs, Seed::DEFAULT_SEED);
+    }
+
     #[test]
     fn from_base58_overflow() {
         let result = Seed::from_base58("JEKNVnkbo3jma5nREBBJCDoXFVeKkD56V3xKrvRmWxFH");
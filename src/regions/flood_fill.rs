@@ -0,0 +1,104 @@
+use std::collections::VecDeque;
+use crate::{collections::Grid, geometry::{Aabb, Adjacency, Point}};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Region<const DIM: usize> {
+    // In breadth-first order from the first cell found
+    pub cells: Vec<Point<DIM>>,
+    pub bounds: Aabb<DIM>,
+    // Cells with an adjacent point outside the region, including points beyond the grid
+    pub border: Vec<Point<DIM>>,
+}
+
+impl<const DIM: usize> Region<DIM> {
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+}
+
+// The region of cells matching `predicate` that are connected to `start`; empty if `start` itself
+// is outside the grid or does not match
+pub fn flood_fill<T, F, const DIM: usize>(
+    grid: &Grid<T, DIM>,
+    start: Point<DIM>,
+    adjacency: Adjacency,
+    predicate: F,
+) -> Region<DIM> where F: Fn(&T) -> bool {
+    let mut is_member = Grid::new(grid.size(), false);
+
+    fill(grid, start, adjacency, &predicate, &mut is_member)
+}
+
+// Fills from `start`, marking cells in `is_member`, which must not already hold any cells connected
+// to `start`
+pub(crate) fn fill<T, F, const DIM: usize>(
+    grid: &Grid<T, DIM>,
+    start: Point<DIM>,
+    adjacency: Adjacency,
+    predicate: &F,
+    is_member: &mut Grid<bool, DIM>,
+) -> Region<DIM> where F: Fn(&T) -> bool {
+    let mut cells = Vec::new();
+    let mut bounds = Aabb::new(start, start);
+
+    if grid.get(start).is_some_and(predicate) {
+        let mut queue = VecDeque::from([start]);
+
+        is_member[start] = true;
+
+        while let Some(cell) = queue.pop_front() {
+            cells.push(cell);
+
+            bounds = bounds.union(&Aabb::with_size(cell, [1; DIM]));
+
+            for neighbor in adjacency.neighbors(cell) {
+                let matches = grid.get(neighbor).is_some_and(predicate);
+
+                if matches && !is_member[neighbor] {
+                    is_member[neighbor] = true;
+                    queue.push_back(neighbor);
+                };
+            };
+        };
+    };
+
+    let border = cells.iter().copied().filter(|&cell| {
+        adjacency.neighbors(cell).any(|neighbor| !is_member.get(neighbor).copied().unwrap_or(false))
+    }).collect();
+
+    Region { cells, bounds, border }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        collections::Grid,
+        geometry::{Aabb, Adjacency, Point},
+        regions::flood_fill,
+    };
+
+    #[test]
+    fn flood_fill_connectivity() {
+        // Two diagonal touching squares of floor
+        let grid = Grid::from_fn([6, 6], |point| {
+            (point[0] < 3 && point[1] < 3) || (point[0] >= 3 && point[1] >= 3)
+        });
+
+        let orthogonal = flood_fill(&grid, Point::new([0, 0]), Adjacency::VonNeumann, |&open| open);
+
+        assert_eq!(orthogonal.len(), 9);
+        assert_eq!(orthogonal.bounds, Aabb::new(Point::new([0, 0]), Point::new([3, 3])));
+        assert_eq!(orthogonal.border.len(), 8);
+
+        let diagonal = flood_fill(&grid, Point::new([0, 0]), Adjacency::Moore, |&open| open);
+
+        assert_eq!(diagonal.len(), 18);
+        assert_eq!(diagonal.bounds, Aabb::new(Point::new([0, 0]), Point::new([6, 6])));
+
+        assert!(flood_fill(&grid, Point::new([4, 0]), Adjacency::Moore, |&open| open).is_empty());
+    }
+}
@@ -0,0 +1,5 @@
+mod flood_fill;
+mod region_labels;
+
+pub use flood_fill::{flood_fill, Region};
+pub use region_labels::RegionLabels;
@@ -0,0 +1,113 @@
+use crate::{
+    collections::Grid,
+    geometry::{Adjacency, Point},
+    regions::{flood_fill::fill, Region},
+};
+
+// Connected-component labeling: every cell matching the predicate is assigned the index of its
+// region. Regions are numbered in the order their first cell appears in the grid.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RegionLabels<const DIM: usize> {
+    labels: Grid<Option<usize>, DIM>,
+    regions: Vec<Region<DIM>>,
+}
+
+impl<const DIM: usize> RegionLabels<DIM> {
+    pub fn new<T, F>(grid: &Grid<T, DIM>, adjacency: Adjacency, predicate: F) -> Self
+    where F: Fn(&T) -> bool {
+        let mut is_member = Grid::new(grid.size(), false);
+        let mut labels = Grid::new(grid.size(), None);
+        let mut regions = Vec::new();
+
+        for (point, value) in grid.iter() {
+            if is_member[point] || !predicate(value) {
+                continue;
+            };
+
+            let region = fill(grid, point, adjacency, &predicate, &mut is_member);
+
+            for &cell in &region.cells {
+                labels[cell] = Some(regions.len());
+            };
+
+            regions.push(region);
+        };
+
+        Self { labels, regions }
+    }
+
+    pub fn labels(&self) -> &Grid<Option<usize>, DIM> {
+        &self.labels
+    }
+
+    pub fn label(&self, point: Point<DIM>) -> Option<usize> {
+        self.labels.get(point).copied().flatten()
+    }
+
+    pub fn regions(&self) -> &[Region<DIM>] {
+        &self.regions
+    }
+
+    pub fn region_at(&self, point: Point<DIM>) -> Option<&Region<DIM>> {
+        self.label(point).map(|label| &self.regions[label])
+    }
+
+    // Ties go to the region found first
+    pub fn largest(&self) -> Option<usize> {
+        (0..self.regions.len()).rev().max_by_key(|&label| self.regions[label].len())
+    }
+
+    // Every region except the largest, such as the orphan pockets left behind by thresholding
+    pub fn disconnected(&self) -> impl Iterator<Item = &Region<DIM>> {
+        let largest = self.largest();
+
+        self.regions.iter().enumerate()
+            .filter(move |&(label, _)| Some(label) != largest)
+            .map(|(_, region)| region)
+    }
+
+    // Mask of the cells belonging to the largest region
+    pub fn largest_mask(&self) -> Grid<bool, DIM> {
+        let largest = self.largest();
+
+        self.labels.map(|&label| label.is_some() && label == largest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{collections::Grid, geometry::{Adjacency, Point}, regions::RegionLabels};
+
+    #[test]
+    fn region_labels() {
+        let map = [
+            "##..#....",
+            "##..#.##.",
+            "....#.##.",
+            "#####....",
+            "..#......",
+        ];
+
+        let grid = Grid::from_fn([9, 5], |point| {
+            map[point[1] as usize].as_bytes()[point[0] as usize] == b'.'
+        });
+
+        let labels = RegionLabels::new(&grid, Adjacency::VonNeumann, |&open| open);
+
+        assert_eq!(labels.regions().len(), 3);
+        assert_eq!(labels.regions().iter().map(|region| region.len()).collect::<Vec<_>>(), vec![8, 18, 2]);
+        assert_eq!(labels.largest(), Some(1));
+        assert_eq!(labels.label(Point::new([0, 4])), Some(2));
+        assert_eq!(labels.label(Point::new([0, 0])), None);
+        assert_eq!(labels.disconnected().count(), 2);
+
+        let mask = labels.largest_mask();
+
+        assert!(mask[Point::new([8, 0])]);
+        assert!(!mask[Point::new([2, 0])]);
+
+        let walls = RegionLabels::new(&grid, Adjacency::Moore, |&open| !open);
+
+        assert_eq!(walls.regions().len(), 3);
+    }
+}
@@ -0,0 +1,74 @@
+use std::path::Path;
+use image::{GrayImage, ImageResult, Luma, Rgb, RgbImage, Rgba, RgbaImage};
+use crate::{color::ColorRamp, noise::{ColorNode, NoiseNode}, render::Viewport};
+
+pub fn render_grayscale<T: NoiseNode<2>>(node: &T, viewport: &Viewport) -> GrayImage {
+    GrayImage::from_fn(viewport.width(), viewport.height(), |x, y| {
+        let value = node.value_at(viewport.point_at(x, y));
+
+        Luma([(value.clamp(0.0, 1.0) * 255.0).round() as u8])
+    })
+}
+
+pub fn render_color_ramp<T: NoiseNode<2>>(node: &T, ramp: &ColorRamp, viewport: &Viewport) -> RgbImage {
+    RgbImage::from_fn(viewport.width(), viewport.height(), |x, y| {
+        Rgb(ramp.color_at(node.value_at(viewport.point_at(x, y))))
+    })
+}
+
+pub fn render_color_node<T: NoiseNode<2>>(node: &ColorNode<2, T>, viewport: &Viewport) -> RgbaImage {
+    RgbaImage::from_fn(viewport.width(), viewport.height(), |x, y| {
+        Rgba(node.rgba_at(viewport.point_at(x, y)))
+    })
+}
+
+// The output format is chosen from the file extension, e.g. `.png` or `.pgm`
+pub fn save_grayscale<T, P>(node: &T, viewport: &Viewport, path: P) -> ImageResult<()>
+where T: NoiseNode<2>, P: AsRef<Path> {
+    render_grayscale(node, viewport).save(path)
+}
+
+pub fn save_color_ramp<T, P>(node: &T, ramp: &ColorRamp, viewport: &Viewport, path: P) -> ImageResult<()>
+where T: NoiseNode<2>, P: AsRef<Path> {
+    render_color_ramp(node, ramp, viewport).save(path)
+}
+
+pub fn save_color_node<T, P>(node: &ColorNode<2, T>, viewport: &Viewport, path: P) -> ImageResult<()>
+where T: NoiseNode<2>, P: AsRef<Path> {
+    render_color_node(node, viewport).save(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        color::{ColorRamp, ColorStop},
+        geometry::RealPoint,
+        noise::{ColorNode, ConstNode},
+        render::{render_color_node, render_color_ramp, render_grayscale, Viewport},
+    };
+
+    #[test]
+    fn render() {
+        let viewport = Viewport::with_scale(RealPoint::new([0.0, 0.0]), 10.0, 4, 3);
+        let node = ConstNode::new(0.5);
+
+        let gray = render_grayscale(&node, &viewport);
+
+        assert_eq!(gray.dimensions(), (4, 3));
+        assert!(gray.pixels().all(|pixel| pixel.0 == [0x80]));
+
+        let ramp = ColorRamp::new(vec![
+            ColorStop::new(0.0, [0x00, 0x00, 0xFF]),
+            ColorStop::new(1.0, [0xFF, 0x00, 0x00]),
+        ]);
+
+        let color = render_color_ramp(&node, &ramp, &viewport);
+
+        assert!(color.pixels().all(|pixel| pixel.0 == [0x80, 0x00, 0x80]));
+
+        let color_node = ColorNode::new(&node, &ramp);
+        let rgba = render_color_node(&color_node, &viewport);
+
+        assert!(rgba.pixels().all(|pixel| pixel.0 == [0x80, 0x00, 0x80, 0xFF]));
+    }
+}
@@ -0,0 +1,12 @@
+mod export;
+mod viewport;
+
+pub use export::{
+    render_color_node,
+    render_color_ramp,
+    render_grayscale,
+    save_color_node,
+    save_color_ramp,
+    save_grayscale,
+};
+pub use viewport::Viewport;
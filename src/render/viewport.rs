@@ -0,0 +1,40 @@
+use crate::geometry::RealPoint;
+
+// Maps the pixels of a `width * height` image onto a rectangle of noise space
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Viewport {
+    origin: RealPoint<2>,
+    size: RealPoint<2>,
+    width: u32,
+    height: u32,
+}
+
+impl Viewport {
+    pub fn new(origin: RealPoint<2>, size: RealPoint<2>, width: u32, height: u32) -> Self {
+        Self { origin, size, width, height }
+    }
+
+    // A viewport covering `width / scale` by `height / scale` units of noise space
+    pub fn with_scale(origin: RealPoint<2>, scale: f64, width: u32, height: u32) -> Self {
+        let size = RealPoint::new([width as f64, height as f64]) / scale;
+
+        Self { origin, size, width, height }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn point_at(&self, x: u32, y: u32) -> RealPoint<2> {
+        let offset = RealPoint::new([
+            (x as f64) / (self.width as f64),
+            (y as f64) / (self.height as f64),
+        ]);
+
+        self.origin + offset * self.size
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{geometry::{Point, RealPoint}, noise::NoiseNode, terrain::Heightmap, utils};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ClimateMaps {
+    pub temperature: Heightmap,
+    // Moisture carried by the air over each cell, in [0.0, 1.0]
+    pub moisture: Heightmap,
+    // Moisture rained out over each cell
+    pub precipitation: Heightmap,
+}
+
+// Derives temperature and precipitation from elevation. Temperature falls with latitude and with
+// height above sea level. Moisture is picked up over the sea and carried downwind, raining out as
+// air is forced up slopes, which leaves rain shadows on the lee side of ranges.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Climate {
+    // Sea-level temperatures at the equator and at the poles
+    pub equator_temperature: f64,
+    pub pole_temperature: f64,
+    // Temperature drop per unit of elevation above sea level
+    pub lapse_rate: f64,
+    pub sea_level: f64,
+    // Latitudes of the top and bottom rows, where 0.0 is the equator and 1.0 or -1.0 a pole
+    pub latitude_range: (f64, f64),
+    // Prevailing wind direction, in cells; only the direction matters
+    pub wind: RealPoint<2>,
+    // Moisture of the air entering the map along its upwind edges
+    pub inflow_moisture: f64,
+    // Fraction of the remaining capacity refilled for each sea cell crossed
+    pub evaporation: f64,
+    // Fraction of moisture rained out for each cell crossed, regardless of terrain
+    pub rainfall: f64,
+    // Additional fraction rained out per unit of elevation gained
+    pub orographic_rainfall: f64,
+}
+
+impl Default for Climate {
+    fn default() -> Self {
+        Self {
+            equator_temperature: 30.0,
+            pole_temperature: -20.0,
+            lapse_rate: 40.0,
+            sea_level: 0.4,
+            latitude_range: (1.0, -1.0),
+            wind: RealPoint::new([1.0, 0.0]),
+            inflow_moisture: 0.5,
+            evaporation: 0.1,
+            rainfall: 0.02,
+            orographic_rainfall: 2.0,
+        }
+    }
+}
+
+impl Climate {
+    pub fn latitude(&self, row: usize, height: usize) -> f64 {
+        let bias = if height > 1 { (row as f64) / ((height - 1) as f64) } else { 0.5 };
+
+        utils::lerp(bias, self.latitude_range.0, self.latitude_range.1)
+    }
+
+    pub fn temperature(&self, latitude: f64, elevation: f64) -> f64 {
+        let sea_level_temperature = utils::lerp(latitude.abs().min(1.0), self.equator_temperature, self.pole_temperature);
+
+        sea_level_temperature - self.lapse_rate * (elevation - self.sea_level).max(0.0)
+    }
+
+    // Samples `node` at `origin + cell * spacing` for every cell and simulates over the result
+    pub fn simulate_node<T: NoiseNode<2>>(
+        &self,
+        node: &T,
+        origin: RealPoint<2>,
+        spacing: f64,
+        width: usize,
+        height: usize,
+    ) -> ClimateMaps {
+        self.simulate(&Heightmap::sample(node, origin, spacing, width, height))
+    }
+
+    pub fn simulate(&self, elevation: &Heightmap) -> ClimateMaps {
+        let width = elevation.width();
+        let height = elevation.height();
+
+        let temperature = Heightmap::from_fn(width, height, |cell| {
+            self.temperature(self.latitude(cell[1] as usize, height), elevation[cell])
+        });
+
+        let mut moisture = Heightmap::new(width, height, 0.0);
+        let mut precipitation = Heightmap::new(width, height, 0.0);
+
+        // First-order upwind advection: every cell draws its air from the orthogonal neighbours
+        // it is downwind of, weighted by the wind components, so cells are processed in order of
+        // their distance along the wind
+        let step = Point::new([sign(self.wind[0]), sign(self.wind[1])]);
+        let weights = [self.wind[0].abs(), self.wind[1].abs()];
+        let total_weight = weights[0] + weights[1];
+
+        let mut order: Vec<Point<2>> = elevation.cells().collect();
+
+        order.sort_by(|a, b| {
+            let lhs = a.to_real_point().dot_prod(self.wind);
+            let rhs = b.to_real_point().dot_prod(self.wind);
+
+            lhs.partial_cmp(&rhs).unwrap()
+        });
+
+        for cell in order {
+            let (mut air_moisture, upwind_elevation) = if total_weight > 0.0 {
+                let mut air_moisture = 0.0;
+                let mut upwind_elevation = 0.0;
+
+                for (axis, &weight) in weights.iter().enumerate() {
+                    let mut upwind = cell;
+
+                    upwind[axis] -= step[axis];
+
+                    let (upwind_moisture, upwind_height) = match elevation.get(upwind) {
+                        Some(upwind_height) => (moisture[upwind], upwind_height),
+                        None => (self.inflow_moisture, elevation[cell]),
+                    };
+
+                    air_moisture += weight * upwind_moisture;
+                    upwind_elevation += weight * upwind_height;
+                };
+
+                (air_moisture / total_weight, upwind_elevation / total_weight)
+            } else {
+                (self.inflow_moisture, elevation[cell])
+            };
+
+            let cell_elevation = elevation[cell].max(self.sea_level);
+
+            if elevation[cell] <= self.sea_level {
+                air_moisture += self.evaporation * (1.0 - air_moisture);
+            };
+
+            let rise = cell_elevation - upwind_elevation.max(self.sea_level);
+            let fraction = (self.rainfall + self.orographic_rainfall * rise.max(0.0)).min(1.0);
+            let rain = air_moisture * fraction;
+
+            moisture[cell] = air_moisture - rain;
+            precipitation[cell] = rain;
+        };
+
+        ClimateMaps { temperature, moisture, precipitation }
+    }
+}
+
+fn sign(value: f64) -> i32 {
+    if value > 0.0 {
+        1
+    } else if value < 0.0 {
+        -1
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{geometry::{Point, RealPoint}, terrain::{Climate, Heightmap}};
+
+    // Sea on the western third, then a north-south range in the middle of the land
+    fn terrain() -> Heightmap {
+        Heightmap::from_fn(30, 10, |cell| {
+            match cell[0] {
+                0..10 => 0.2,
+                17..20 => 0.9,
+                _ => 0.5,
+            }
+        })
+    }
+
+    #[test]
+    fn temperature() {
+        let climate = Climate::default();
+        let maps = climate.simulate(&terrain());
+
+        assert!(maps.temperature[Point::new([12, 5])] > maps.temperature[Point::new([12, 0])]);
+        assert!(maps.temperature[Point::new([12, 5])] > maps.temperature[Point::new([18, 5])]);
+        assert_eq!(maps.temperature[Point::new([0, 0])], climate.pole_temperature);
+    }
+
+    #[test]
+    fn rain_shadow() {
+        let maps = Climate::default().simulate(&terrain());
+
+        let windward = maps.precipitation[Point::new([17, 5])];
+        let before = maps.precipitation[Point::new([14, 5])];
+        let leeward = maps.precipitation[Point::new([24, 5])];
+
+        assert!(windward > before);
+        assert!(before > leeward);
+
+        for cell in terrain().cells() {
+            assert!((0.0..=1.0).contains(&maps.moisture[cell]));
+        };
+
+        // Reversing the wind moves the shadow to the other side of the range
+        let climate = Climate { wind: RealPoint::new([-1.0, 0.0]), ..Climate::default() };
+        let maps = climate.simulate(&terrain());
+
+        assert!(maps.precipitation[Point::new([19, 5])] > maps.precipitation[Point::new([14, 5])]);
+    }
+}
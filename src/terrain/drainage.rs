@@ -0,0 +1,362 @@
+use crate::{collections::{Grid, PointQueue}, geometry::{DistanceMetric, Point}, terrain::Heightmap};
+
+// Height added per step when raising flats, so that every filled cell drains towards an outlet
+const FILL_EPSILON: f64 = 1e-9;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum FlowRouting {
+    // All flow goes to the steepest of the eight neighbours
+    D8,
+    // Flow is split between the two neighbours bounding the steepest downhill direction (Tarboton).
+    // Falls back to D8 under metrics where the diagonal step is not the hypotenuse of the orthogonal
+    // ones, such as Chebyshev or Manhattan, since the triangular facets are then degenerate.
+    DInfinity,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Lake {
+    pub cells: Vec<Point<2>>,
+    pub surface: f64,
+}
+
+// Per-cell list of downstream neighbours and the fraction of flow each receives. Outlets, such as
+// cells at or below sea level, have no receivers.
+#[derive(Clone, PartialEq, Debug)]
+pub struct FlowField {
+    receivers: Grid<Vec<(Point<2>, f64)>, 2>,
+    order: Vec<Point<2>>,
+}
+
+// Priority-flood depression filling (Barnes et al. 2014). Border cells and cells at or below sea
+// level act as outlets; every other cell is raised until it has a strictly descending path to one.
+pub fn fill_depressions(heightmap: &Heightmap, sea_level: f64) -> Heightmap {
+    let mut filled = heightmap.clone();
+    let mut visited = Grid::new([heightmap.width(), heightmap.height()], false);
+    let mut queue = PointQueue::new();
+
+    let width = heightmap.width() as i32;
+    let height = heightmap.height() as i32;
+
+    for cell in heightmap.cells() {
+        let is_border = cell[0] == 0 || cell[1] == 0 || cell[0] == width - 1 || cell[1] == height - 1;
+
+        if is_border || heightmap[cell] <= sea_level {
+            visited[cell] = true;
+            queue.push(cell, heightmap[cell]);
+        };
+    };
+
+    while let Some((cell, height)) = queue.pop() {
+        for neighbor in cell.neighbors() {
+            if !visited.get(neighbor).is_some_and(|&visited| !visited) {
+                continue;
+            };
+
+            visited[neighbor] = true;
+
+            if filled[neighbor] <= height {
+                filled[neighbor] = height + FILL_EPSILON.max(height.abs() * f64::EPSILON * 4.0);
+            };
+
+            queue.push(neighbor, filled[neighbor]);
+        };
+    };
+
+    filled
+}
+
+// Computes flow directions over a depression-free heightmap, as produced by `fill_depressions`. The
+// metric determines the length of a diagonal step, and so how steep diagonal slopes are.
+pub fn flow_directions<T>(heightmap: &Heightmap, routing: FlowRouting, sea_level: f64) -> FlowField
+where T: DistanceMetric {
+    let receivers = Grid::from_fn([heightmap.width(), heightmap.height()], |cell| {
+        if heightmap[cell] <= sea_level {
+            return Vec::new();
+        };
+
+        match routing {
+            FlowRouting::D8 => d8_receiver::<T>(heightmap, cell).into_iter().map(|receiver| (receiver, 1.0)).collect(),
+            FlowRouting::DInfinity => d_infinity_receivers::<T>(heightmap, cell),
+        }
+    });
+
+    let mut order: Vec<Point<2>> = heightmap.cells().collect();
+
+    order.sort_by(|&a, &b| heightmap[b].partial_cmp(&heightmap[a]).unwrap());
+
+    FlowField { receivers, order }
+}
+
+fn d8_receiver<T: DistanceMetric>(heightmap: &Heightmap, cell: Point<2>) -> Option<Point<2>> {
+    let height = heightmap[cell];
+
+    cell.neighbors().filter_map(|neighbor| {
+        let drop = height - heightmap.get(neighbor)?;
+        let slope = drop / (neighbor - cell).to_real_point().magnitude::<T>();
+
+        if drop > 0.0 { Some((neighbor, slope)) } else { None }
+    }).fold(None, |best: Option<(Point<2>, f64)>, candidate| {
+        match best {
+            Some((_, slope)) if slope >= candidate.1 => best,
+            _ => Some(candidate),
+        }
+    }).map(|(neighbor, _)| neighbor)
+}
+
+// Each facet is a triangle formed by the cell, an orthogonal neighbour and the diagonal neighbour
+// adjacent to both
+const FACETS: [([i32; 2], [i32; 2]); 8] = [
+    ([1, 0], [1, -1]),
+    ([0, -1], [1, -1]),
+    ([0, -1], [-1, -1]),
+    ([-1, 0], [-1, -1]),
+    ([-1, 0], [-1, 1]),
+    ([0, 1], [-1, 1]),
+    ([0, 1], [1, 1]),
+    ([1, 0], [1, 1]),
+];
+
+fn d_infinity_receivers<T: DistanceMetric>(heightmap: &Heightmap, cell: Point<2>) -> Vec<(Point<2>, f64)> {
+    let height = heightmap[cell];
+    let diagonal = Point::new([1, 1]).to_real_point().magnitude::<T>();
+    let orthogonal = Point::new([1, 0]).to_real_point().magnitude::<T>();
+
+    if (diagonal * diagonal - 2.0 * orthogonal * orthogonal).abs() > 1e-9 * diagonal * diagonal {
+        return d8_receiver::<T>(heightmap, cell).into_iter().map(|receiver| (receiver, 1.0)).collect();
+    };
+
+    let max_angle = std::f64::consts::FRAC_PI_4;
+
+    let mut best: Option<(f64, Point<2>, Point<2>, f64)> = None;
+
+    for (orthogonal_offset, diagonal_offset) in FACETS {
+        let orthogonal_cell = cell + Point::new(orthogonal_offset);
+        let diagonal_cell = cell + Point::new(diagonal_offset);
+
+        let (Some(e1), Some(e2)) = (heightmap.get(orthogonal_cell), heightmap.get(diagonal_cell)) else {
+            continue;
+        };
+
+        let s1 = (height - e1) / orthogonal;
+        let s2 = (e1 - e2) / orthogonal;
+
+        let mut angle = s2.atan2(s1);
+        let mut slope = s1.hypot(s2);
+
+        if angle <= 0.0 {
+            angle = 0.0;
+            slope = s1;
+        } else if angle >= max_angle {
+            angle = max_angle;
+            slope = (height - e2) / diagonal;
+        };
+
+        if slope > 0.0 && best.is_none_or(|(best_slope, ..)| slope > best_slope) {
+            best = Some((slope, orthogonal_cell, diagonal_cell, angle / max_angle));
+        };
+    };
+
+    match best {
+        None => Vec::new(),
+        Some((_, orthogonal_cell, _, fraction)) if fraction <= 0.0 => vec![(orthogonal_cell, 1.0)],
+        Some((_, _, diagonal_cell, fraction)) if fraction >= 1.0 => vec![(diagonal_cell, 1.0)],
+        Some((_, orthogonal_cell, diagonal_cell, fraction)) => {
+            vec![(orthogonal_cell, 1.0 - fraction), (diagonal_cell, fraction)]
+        },
+    }
+}
+
+impl FlowField {
+    pub fn receivers(&self, cell: Point<2>) -> &[(Point<2>, f64)] {
+        &self.receivers[cell]
+    }
+
+    // The receiver taking the largest share of a cell's flow
+    pub fn main_receiver(&self, cell: Point<2>) -> Option<Point<2>> {
+        self.receivers(cell).iter()
+            .fold(None, |best: Option<(Point<2>, f64)>, &(receiver, fraction)| match best {
+                Some((_, best_fraction)) if best_fraction >= fraction => best,
+                _ => Some((receiver, fraction)),
+            })
+            .map(|(receiver, _)| receiver)
+    }
+
+    // Number of cells draining through each cell, including itself
+    pub fn accumulation(&self) -> Heightmap {
+        let [width, height] = self.receivers.size();
+        let mut accumulation = Heightmap::new(width, height, 1.0);
+
+        for &cell in &self.order {
+            let flow = accumulation[cell];
+
+            for &(receiver, fraction) in self.receivers(cell) {
+                accumulation[receiver] += flow * fraction;
+            };
+        };
+
+        accumulation
+    }
+
+    // Traces rivers through every cell whose accumulation meets the threshold. Each polyline runs
+    // downstream from a source, or from just below a confluence, and ends at an outlet or where it
+    // joins a river that has already been traced; the joining cell is included in both.
+    pub fn rivers(&self, accumulation: &Heightmap, threshold: f64) -> Vec<Vec<Point<2>>> {
+        let mut has_donor = Grid::new(self.receivers.size(), false);
+
+        for &cell in &self.order {
+            if accumulation[cell] >= threshold {
+                if let Some(receiver) = self.main_receiver(cell) {
+                    has_donor[receiver] = true;
+                };
+            };
+        };
+
+        let mut visited = Grid::new(self.receivers.size(), false);
+        let mut rivers = Vec::new();
+
+        for &cell in &self.order {
+            if accumulation[cell] < threshold || has_donor[cell] || visited[cell] {
+                continue;
+            };
+
+            let mut river = vec![cell];
+            let mut current = cell;
+
+            visited[cell] = true;
+
+            while let Some(next) = self.main_receiver(current) {
+                river.push(next);
+
+                if visited[next] {
+                    break;
+                };
+
+                visited[next] = true;
+                current = next;
+            };
+
+            rivers.push(river);
+        };
+
+        rivers
+    }
+}
+
+// Groups cells raised by depression filling into connected lakes
+pub fn find_lakes(original: &Heightmap, filled: &Heightmap) -> Vec<Lake> {
+    let mut assigned = Grid::new([original.width(), original.height()], false);
+    let mut lakes = Vec::new();
+
+    for cell in original.cells() {
+        if assigned[cell] || filled[cell] <= original[cell] {
+            continue;
+        };
+
+        let mut cells = Vec::new();
+        let mut stack = vec![cell];
+        let mut surface = f64::NEG_INFINITY;
+
+        assigned[cell] = true;
+
+        while let Some(current) = stack.pop() {
+            cells.push(current);
+            surface = surface.max(filled[current]);
+
+            for neighbor in current.neighbors() {
+                if !assigned.get(neighbor).is_some_and(|&assigned| !assigned) {
+                    continue;
+                };
+
+                if filled[neighbor] > original[neighbor] {
+                    assigned[neighbor] = true;
+                    stack.push(neighbor);
+                };
+            };
+        };
+
+        lakes.push(Lake { cells, surface });
+    };
+
+    lakes
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{ChebyshevMetric, DistanceMetric, EuclideanMetric, ManhattanMetric, Point},
+        terrain::{fill_depressions, find_lakes, flow_directions, FlowRouting, Heightmap},
+    };
+
+    // A bowl sloping down towards the left edge, with a pit in the middle
+    fn terrain() -> Heightmap {
+        Heightmap::from_fn(8, 8, |cell| {
+            let pit = if cell == Point::new([4, 4]) || cell == Point::new([4, 3]) { -5.0 } else { 0.0 };
+
+            (cell[0] as f64) + 0.1 * ((cell[1] - 4) as f64).abs() + pit
+        })
+    }
+
+    #[test]
+    fn fill_and_lakes() {
+        let original = terrain();
+        let filled = fill_depressions(&original, f64::NEG_INFINITY);
+
+        assert!(filled[Point::new([4, 4])] >= 3.0);
+        assert_eq!(filled[Point::new([0, 0])], original[Point::new([0, 0])]);
+
+        let lakes = find_lakes(&original, &filled);
+
+        assert_eq!(lakes.len(), 1);
+        assert_eq!(lakes[0].cells.len(), 2);
+    }
+
+    fn check_accumulation<T: DistanceMetric>() {
+        let filled = fill_depressions(&terrain(), f64::NEG_INFINITY);
+
+        for routing in [FlowRouting::D8, FlowRouting::DInfinity] {
+            let flow = flow_directions::<T>(&filled, routing, f64::NEG_INFINITY);
+            let accumulation = flow.accumulation();
+
+            for cell in filled.cells() {
+                let receivers = flow.receivers(cell);
+                let total: f64 = receivers.iter().map(|&(_, fraction)| fraction).sum();
+
+                assert!(receivers.iter().all(|&(_, fraction)| fraction.is_finite() && fraction > 0.0));
+                assert!(receivers.is_empty() || (total - 1.0).abs() < 1e-9, "{:?}: {:?}", cell, receivers);
+            };
+
+            // Every cell drains somewhere, so the total arriving at outlets equals the cell count
+            let outlets: f64 = filled.cells()
+                .filter(|&cell| flow.receivers(cell).is_empty())
+                .map(|cell| accumulation[cell])
+                .sum();
+
+            assert!((outlets - 64.0).abs() < 1e-9, "{:?}: {}", routing, outlets);
+
+            for river in flow.rivers(&accumulation, 4.0) {
+                for pair in river.windows(2) {
+                    assert!(filled[pair[1]] < filled[pair[0]]);
+                };
+            };
+        };
+    }
+
+    #[test]
+    fn accumulation_and_rivers() {
+        check_accumulation::<EuclideanMetric>();
+        check_accumulation::<ChebyshevMetric>();
+        check_accumulation::<ManhattanMetric>();
+    }
+
+    #[test]
+    fn sea_level_outlets() {
+        let heightmap = Heightmap::from_fn(6, 1, |cell| (cell[0] as f64) - 2.0);
+        let filled = fill_depressions(&heightmap, 0.0);
+        let flow = flow_directions::<EuclideanMetric>(&filled, FlowRouting::D8, 0.0);
+
+        assert!(flow.receivers(Point::new([1, 0])).is_empty());
+        assert_eq!(flow.rivers(&flow.accumulation(), 2.0), vec![
+            vec![Point::new([4, 0]), Point::new([3, 0]), Point::new([2, 0])],
+        ]);
+    }
+}
@@ -0,0 +1,193 @@
+use std::ops::{Index, IndexMut};
+use crate::{collections::Grid, geometry::{Point, RealPoint}, noise::NoiseNode, utils};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Interpolation {
+    Nearest,
+    Linear,
+    Cubic,
+}
+
+// How samples beyond the edges of a heightmap are resolved
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum EdgeMode {
+    Clamp,
+    Wrap,
+}
+
+// Dense 2D field of heights, indexed by cell
+#[derive(Clone, PartialEq, Debug)]
+pub struct Heightmap {
+    grid: Grid<f64, 2>,
+}
+
+impl Heightmap {
+    pub fn new(width: usize, height: usize, value: f64) -> Self {
+        Self { grid: Grid::new([width, height], value) }
+    }
+
+    pub fn from_fn<F>(width: usize, height: usize, f: F) -> Self where F: FnMut(Point<2>) -> f64 {
+        Self { grid: Grid::from_fn([width, height], f) }
+    }
+
+    // Samples `node` at `origin + cell * spacing` for every cell
+    pub fn sample<T: NoiseNode<2>>(
+        node: &T,
+        origin: RealPoint<2>,
+        spacing: f64,
+        width: usize,
+        height: usize,
+    ) -> Self {
+        Self { grid: Grid::sample(node, origin, spacing, [width, height]) }
+    }
+
+    pub fn width(&self) -> usize {
+        self.grid.size()[0]
+    }
+
+    pub fn height(&self) -> usize {
+        self.grid.size()[1]
+    }
+
+    pub fn values(&self) -> &[f64] {
+        self.grid.values()
+    }
+
+    pub fn values_mut(&mut self) -> &mut [f64] {
+        self.grid.values_mut()
+    }
+
+    pub fn as_grid(&self) -> &Grid<f64, 2> {
+        &self.grid
+    }
+
+    pub fn into_grid(self) -> Grid<f64, 2> {
+        self.grid
+    }
+
+    pub fn contains(&self, cell: Point<2>) -> bool {
+        self.grid.contains(cell)
+    }
+
+    pub fn get(&self, cell: Point<2>) -> Option<f64> {
+        self.grid.get(cell).copied()
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = Point<2>> {
+        self.grid.points()
+    }
+
+    pub fn min_max(&self) -> (f64, f64) {
+        self.values().iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| {
+            (min.min(value), max.max(value))
+        })
+    }
+
+    // Linearly rescales all values onto [0.0, 1.0]
+    pub fn normalize(&mut self) {
+        let (min, max) = self.min_max();
+        let range = max - min;
+
+        for value in self.values_mut() {
+            *value = if range > 0.0 { (*value - min) / range } else { 0.0 };
+        };
+    }
+
+    pub fn value_at(&self, cell: Point<2>, edge_mode: EdgeMode) -> f64 {
+        let resolve = |coordinate: i32, length: usize| -> usize {
+            match edge_mode {
+                EdgeMode::Clamp => coordinate.clamp(0, length as i32 - 1) as usize,
+                EdgeMode::Wrap => coordinate.rem_euclid(length as i32) as usize,
+            }
+        };
+
+        let cell = Point::new([resolve(cell[0], self.width()) as i32, resolve(cell[1], self.height()) as i32]);
+
+        self.grid[cell]
+    }
+
+    // Cell centres lie on integer coordinates
+    pub fn interpolate(&self, point: RealPoint<2>, interpolation: Interpolation, edge_mode: EdgeMode) -> f64 {
+        match interpolation {
+            Interpolation::Nearest => self.value_at(point.round().to_lattice_point(), edge_mode),
+
+            Interpolation::Linear => {
+                let base = point.to_lattice_point();
+                let bias = point - point.floor();
+
+                let row = |dy: i32| {
+                    let lhs = self.value_at(base + Point::new([0, dy]), edge_mode);
+                    let rhs = self.value_at(base + Point::new([1, dy]), edge_mode);
+
+                    utils::lerp(bias[0], lhs, rhs)
+                };
+
+                utils::lerp(bias[1], row(0), row(1))
+            },
+
+            Interpolation::Cubic => {
+                let base = point.to_lattice_point();
+                let bias = point - point.floor();
+
+                let row = |dy: i32| {
+                    let samples = [-1, 0, 1, 2].map(|dx| {
+                        self.value_at(base + Point::new([dx, dy]), edge_mode)
+                    });
+
+                    catmull_rom(bias[0], samples)
+                };
+
+                catmull_rom(bias[1], [row(-1), row(0), row(1), row(2)])
+            },
+        }
+    }
+}
+
+fn catmull_rom(t: f64, [p0, p1, p2, p3]: [f64; 4]) -> f64 {
+    let a = -0.5 * p0 + 1.5 * p1 - 1.5 * p2 + 0.5 * p3;
+    let b = p0 - 2.5 * p1 + 2.0 * p2 - 0.5 * p3;
+    let c = -0.5 * p0 + 0.5 * p2;
+
+    ((a * t + b) * t + c) * t + p1
+}
+
+impl From<Grid<f64, 2>> for Heightmap {
+    fn from(grid: Grid<f64, 2>) -> Self {
+        Self { grid }
+    }
+}
+
+impl Index<Point<2>> for Heightmap {
+    type Output = f64;
+
+    fn index(&self, cell: Point<2>) -> &Self::Output {
+        &self.grid[cell]
+    }
+}
+
+impl IndexMut<Point<2>> for Heightmap {
+    fn index_mut(&mut self, cell: Point<2>) -> &mut Self::Output {
+        &mut self.grid[cell]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{geometry::{Point, RealPoint}, terrain::{EdgeMode, Heightmap, Interpolation}};
+
+    #[test]
+    fn interpolate() {
+        let heightmap = Heightmap::from_fn(4, 4, |cell| (cell[0] + 4 * cell[1]) as f64);
+        let point = RealPoint::new([1.25, 1.5]);
+
+        assert_eq!(heightmap[Point::new([1, 2])], 9.0);
+        assert_eq!(heightmap.interpolate(point, Interpolation::Nearest, EdgeMode::Clamp), 9.0);
+        assert_eq!(heightmap.interpolate(point, Interpolation::Linear, EdgeMode::Clamp), 7.25);
+        assert!((heightmap.interpolate(point, Interpolation::Cubic, EdgeMode::Clamp) - 7.25).abs() < 1e-12);
+
+        let edge = RealPoint::new([3.5, 0.0]);
+
+        assert_eq!(heightmap.interpolate(edge, Interpolation::Linear, EdgeMode::Clamp), 3.0);
+        assert_eq!(heightmap.interpolate(edge, Interpolation::Linear, EdgeMode::Wrap), 1.5);
+    }
+}
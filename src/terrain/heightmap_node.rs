@@ -0,0 +1,20 @@
+use crate::{geometry::RealPoint, noise::NoiseNode, terrain::{EdgeMode, Heightmap, Interpolation}};
+
+// Exposes a heightmap as a noise node, with one unit of noise space per cell
+pub struct HeightmapNode<'a> {
+    heightmap: &'a Heightmap,
+    interpolation: Interpolation,
+    edge_mode: EdgeMode,
+}
+
+impl<'a> HeightmapNode<'a> {
+    pub fn new(heightmap: &'a Heightmap, interpolation: Interpolation, edge_mode: EdgeMode) -> Self {
+        Self { heightmap, interpolation, edge_mode }
+    }
+}
+
+impl<'a> NoiseNode<2> for HeightmapNode<'a> {
+    fn value_at(&self, point: RealPoint<2>) -> f64 {
+        self.heightmap.interpolate(point, self.interpolation, self.edge_mode)
+    }
+}
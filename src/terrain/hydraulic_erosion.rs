@@ -0,0 +1,236 @@
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use crate::{
+    geometry::{EuclideanMetric, Point, RealPoint},
+    random::{Seed, Seedable},
+    terrain::Heightmap,
+};
+
+// Particle-based hydraulic erosion: droplets are spawned at random, roll downhill picking up
+// sediment while they are fast and carrying little, and drop it again as they slow down or evaporate
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct HydraulicErosion {
+    pub num_droplets: usize,
+    pub max_lifetime: usize,
+    // How strongly a droplet keeps its previous direction, in [0.0, 1.0]
+    pub inertia: f64,
+    // Sediment a droplet can carry per unit of speed, water and slope
+    pub capacity: f64,
+    pub min_capacity: f64,
+    // Fraction of excess sediment dropped per step
+    pub deposition: f64,
+    // Fraction of spare capacity filled by eroding the terrain per step
+    pub erosion: f64,
+    // Fraction of water lost per step
+    pub evaporation: f64,
+    // Radius, in cells, of the brush used to erode terrain
+    pub radius: usize,
+    pub gravity: f64,
+}
+
+impl Default for HydraulicErosion {
+    fn default() -> Self {
+        Self {
+            num_droplets: 50_000,
+            max_lifetime: 30,
+            inertia: 0.05,
+            capacity: 4.0,
+            min_capacity: 0.01,
+            deposition: 0.3,
+            erosion: 0.3,
+            evaporation: 0.01,
+            radius: 3,
+            gravity: 4.0,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ErosionResult {
+    pub heightmap: Heightmap,
+    // Total material deposited in each cell
+    pub sediment: Heightmap,
+    // Total water carried through each cell
+    pub flow: Heightmap,
+}
+
+impl HydraulicErosion {
+    pub fn erode(&self, heightmap: &Heightmap, seed: &Seed) -> ErosionResult {
+        let width = heightmap.width();
+        let height = heightmap.height();
+
+        let mut rng = ChaCha8Rng::from_seed(seed);
+        let mut heightmap = heightmap.clone();
+        let mut sediment_map = Heightmap::new(width, height, 0.0);
+        let mut flow_map = Heightmap::new(width, height, 0.0);
+
+        if width < 2 || height < 2 {
+            return ErosionResult { heightmap, sediment: sediment_map, flow: flow_map };
+        };
+
+        let brush = self.brush();
+        let max_x = (width - 1) as f64;
+        let max_y = (height - 1) as f64;
+
+        for _ in 0..self.num_droplets {
+            let mut position = RealPoint::new([rng.gen_range(0.0..max_x), rng.gen_range(0.0..max_y)]);
+            let mut direction = RealPoint::new([0.0, 0.0]);
+            let mut speed = 1.0f64;
+            let mut water = 1.0f64;
+            let mut sediment = 0.0f64;
+
+            for _ in 0..self.max_lifetime {
+                let cell = position.to_lattice_point();
+                let (current_height, gradient) = height_and_gradient(&heightmap, position);
+
+                direction = direction * self.inertia - gradient * (1.0 - self.inertia);
+
+                let length = direction.magnitude::<EuclideanMetric>();
+
+                if length == 0.0 {
+                    break;
+                };
+
+                direction /= length;
+
+                let previous = position;
+                position += direction;
+
+                if !(0.0..max_x).contains(&position[0]) || !(0.0..max_y).contains(&position[1]) {
+                    break;
+                };
+
+                flow_map[cell] += water;
+
+                let (new_height, _) = height_and_gradient(&heightmap, position);
+                let delta_height = new_height - current_height;
+
+                let capacity = (-delta_height * speed * water * self.capacity).max(self.min_capacity);
+
+                if sediment > capacity || delta_height > 0.0 {
+                    // Fill the pit being climbed out of, or shed sediment beyond capacity
+                    let amount = if delta_height > 0.0 {
+                        delta_height.min(sediment)
+                    } else {
+                        (sediment - capacity) * self.deposition
+                    };
+
+                    sediment -= amount;
+                    deposit(&mut heightmap, &mut sediment_map, previous, amount);
+                } else {
+                    let amount = ((capacity - sediment) * self.erosion).min(-delta_height);
+
+                    for &(offset, weight) in &brush {
+                        if let Some(value) = heightmap.get(cell + offset) {
+                            let eroded = (amount * weight).min(value.max(0.0));
+
+                            heightmap[cell + offset] -= eroded;
+                            sediment += eroded;
+                        };
+                    };
+                };
+
+                speed = (speed * speed - delta_height * self.gravity).max(0.0).sqrt();
+                water *= 1.0 - self.evaporation;
+            };
+        };
+
+        ErosionResult { heightmap, sediment: sediment_map, flow: flow_map }
+    }
+
+    // Cells within the erosion radius, weighted by proximity and normalized to sum to 1.0
+    fn brush(&self) -> Vec<(Point<2>, f64)> {
+        let radius = self.radius.max(1) as i32;
+
+        let mut brush: Vec<(Point<2>, f64)> = Vec::new();
+
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                let offset = Point::new([dx, dy]);
+                let distance = offset.to_real_point().magnitude::<EuclideanMetric>();
+                let weight = (radius as f64) - distance;
+
+                if weight > 0.0 {
+                    brush.push((offset, weight));
+                };
+            };
+        };
+
+        let total: f64 = brush.iter().map(|&(_, weight)| weight).sum();
+
+        for (_, weight) in brush.iter_mut() {
+            *weight /= total;
+        };
+
+        brush
+    }
+}
+
+fn height_and_gradient(heightmap: &Heightmap, position: RealPoint<2>) -> (f64, RealPoint<2>) {
+    let cell = position.to_lattice_point();
+    let [u, v] = [position[0] - (cell[0] as f64), position[1] - (cell[1] as f64)];
+
+    let h00 = heightmap[cell];
+    let h10 = heightmap[cell + Point::new([1, 0])];
+    let h01 = heightmap[cell + Point::new([0, 1])];
+    let h11 = heightmap[cell + Point::new([1, 1])];
+
+    let gradient = RealPoint::new([
+        (h10 - h00) * (1.0 - v) + (h11 - h01) * v,
+        (h01 - h00) * (1.0 - u) + (h11 - h10) * u,
+    ]);
+
+    let height = h00 * (1.0 - u) * (1.0 - v) + h10 * u * (1.0 - v) + h01 * (1.0 - u) * v + h11 * u * v;
+
+    (height, gradient)
+}
+
+fn deposit(heightmap: &mut Heightmap, sediment_map: &mut Heightmap, position: RealPoint<2>, amount: f64) {
+    let cell = position.to_lattice_point();
+    let [u, v] = [position[0] - (cell[0] as f64), position[1] - (cell[1] as f64)];
+
+    let weights = [
+        (Point::new([0, 0]), (1.0 - u) * (1.0 - v)),
+        (Point::new([1, 0]), u * (1.0 - v)),
+        (Point::new([0, 1]), (1.0 - u) * v),
+        (Point::new([1, 1]), u * v),
+    ];
+
+    for (offset, weight) in weights {
+        heightmap[cell + offset] += amount * weight;
+        sediment_map[cell + offset] += amount * weight;
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::RealPoint,
+        noise::{HarmonicNode, PerlinNode},
+        random::{Seed, Wyhash},
+        terrain::{Heightmap, HydraulicErosion},
+    };
+
+    #[test]
+    fn erode() {
+        let perlin: PerlinNode<2, Wyhash> = PerlinNode::new(&Seed::DEFAULT_SEED);
+        let harmonic = HarmonicNode::new(&perlin, 4, 0.5, 2.0);
+        let heightmap = Heightmap::sample(&harmonic, RealPoint::new([0.5, 0.5]), 0.05, 64, 64);
+
+        let erosion = HydraulicErosion { num_droplets: 2_000, ..HydraulicErosion::default() };
+        let result = erosion.erode(&heightmap, &Seed::DEFAULT_SEED);
+
+        assert_eq!(result, erosion.erode(&heightmap, &Seed::DEFAULT_SEED));
+        assert_ne!(result.heightmap, heightmap);
+
+        let before: f64 = heightmap.values().iter().sum();
+        let after: f64 = result.heightmap.values().iter().sum();
+
+        // Droplets which die or leave the map take their sediment with them, but never add any
+        assert!(after <= before + 1e-9);
+        assert!(result.sediment.values().iter().all(|&value| value >= 0.0));
+        assert!(result.sediment.values().iter().any(|&value| value > 0.0));
+        assert!(result.flow.values().iter().all(|&value| value >= 0.0));
+        assert!(result.flow.values().iter().any(|&value| value > 1.0));
+    }
+}
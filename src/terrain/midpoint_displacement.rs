@@ -0,0 +1,201 @@
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use crate::{geometry::Point, random::{Seed, Seedable}, terrain::Heightmap};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum DisplacementMethod {
+    // Edge midpoints average the four points of the diamond surrounding them during the square step
+    DiamondSquare,
+    // Edge midpoints average only the two corners of the edge they bisect
+    Midpoint,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Edge {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+// Recursive subdivision heightmap generator, filling a square grid of `2^detail + 1` cells per side.
+// The displacement amplitude starts at 0.5 and is multiplied by `roughness` at every level, so
+// values near 0.0 give smooth terrain and values near 1.0 jagged terrain. Pinned values are never
+// displaced, which allows e.g. forcing the borders of an arena down to sea level.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MidpointDisplacement {
+    detail: u32,
+    roughness: f64,
+    method: DisplacementMethod,
+    corners: [Option<f64>; 4],
+    edges: [Option<f64>; 4],
+}
+
+impl MidpointDisplacement {
+    pub fn new(detail: u32, roughness: f64, method: DisplacementMethod) -> Self {
+        Self { detail, roughness, method, corners: [None; 4], edges: [None; 4] }
+    }
+
+    pub fn with_corner(mut self, corner: Corner, value: f64) -> Self {
+        self.corners[corner as usize] = Some(value);
+        self
+    }
+
+    pub fn with_edge(mut self, edge: Edge, value: f64) -> Self {
+        self.edges[edge as usize] = Some(value);
+        self
+    }
+
+    pub fn size(&self) -> usize {
+        (1usize << self.detail) + 1
+    }
+
+    pub fn generate(&self, seed: &Seed) -> Heightmap {
+        let size = self.size();
+        let last = (size - 1) as i32;
+
+        let mut rng = ChaCha8Rng::from_seed(seed);
+        let mut heightmap = Heightmap::new(size, size, 0.0);
+        let mut pinned = Heightmap::new(size, size, f64::NAN);
+
+        for (edge, value) in self.edges.iter().enumerate() {
+            let Some(value) = *value else { continue };
+
+            for idx in 0..=last {
+                let cell = match edge {
+                    0 => Point::new([idx, 0]),
+                    1 => Point::new([idx, last]),
+                    2 => Point::new([0, idx]),
+                    _ => Point::new([last, idx]),
+                };
+
+                pinned[cell] = value;
+            };
+        };
+
+        let corner_cells = [
+            Point::new([0, 0]),
+            Point::new([last, 0]),
+            Point::new([0, last]),
+            Point::new([last, last]),
+        ];
+
+        for (&cell, value) in corner_cells.iter().zip(self.corners.iter()) {
+            if let Some(value) = *value {
+                pinned[cell] = value;
+            };
+        };
+
+        let set = |heightmap: &mut Heightmap, cell: Point<2>, value: f64| {
+            let pin = pinned[cell];
+
+            heightmap[cell] = if pin.is_nan() { value } else { pin };
+        };
+
+        for cell in corner_cells {
+            let value = rng.gen_range(0.0..1.0);
+
+            set(&mut heightmap, cell, value);
+        };
+
+        let mut amplitude = 0.5f64;
+        let mut step = last;
+
+        while step > 1 {
+            let half = step / 2;
+
+            // Diamond step: the centre of each square from its four corners
+            for y in (half..last).step_by(step as usize) {
+                for x in (half..last).step_by(step as usize) {
+                    let average = [(-half, -half), (half, -half), (-half, half), (half, half)].iter()
+                        .map(|&(dx, dy)| heightmap[Point::new([x + dx, y + dy])])
+                        .sum::<f64>() / 4.0;
+
+                    let value = average + rng.gen_range(-amplitude..=amplitude);
+
+                    set(&mut heightmap, Point::new([x, y]), value);
+                };
+            };
+
+            // Square step: the midpoint of each edge
+            for y in (0..=last).step_by(half as usize) {
+                let offset = if (y / half) % 2 == 0 { half } else { 0 };
+
+                for x in (offset..=last).step_by(step as usize) {
+                    let cell = Point::new([x, y]);
+
+                    let neighbors: &[(i32, i32)] = match self.method {
+                        DisplacementMethod::DiamondSquare => &[(0, -1), (0, 1), (-1, 0), (1, 0)],
+                        DisplacementMethod::Midpoint if offset == 0 => &[(0, -1), (0, 1)],
+                        DisplacementMethod::Midpoint => &[(-1, 0), (1, 0)],
+                    };
+
+                    let (total, count) = neighbors.iter().fold((0.0f64, 0usize), |(total, count), &(dx, dy)| {
+                        match heightmap.get(cell + Point::new([dx * half, dy * half])) {
+                            Some(value) => (total + value, count + 1),
+                            None => (total, count),
+                        }
+                    });
+
+                    let value = total / (count as f64) + rng.gen_range(-amplitude..=amplitude);
+
+                    set(&mut heightmap, cell, value);
+                };
+            };
+
+            amplitude *= self.roughness;
+            step = half;
+        };
+
+        heightmap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::Point,
+        random::Seed,
+        terrain::{Corner, DisplacementMethod, Edge, Heightmap, MidpointDisplacement},
+    };
+
+    fn mean_gradient(heightmap: &Heightmap) -> f64 {
+        let cells: Vec<Point<2>> = heightmap.cells().filter(|cell| (cell[0] as usize) + 1 < heightmap.width()).collect();
+
+        cells.iter().map(|&cell| (heightmap[cell + Point::new([1, 0])] - heightmap[cell]).abs()).sum::<f64>()
+            / (cells.len() as f64)
+    }
+
+    #[test]
+    fn pinned_values() {
+        let generator = MidpointDisplacement::new(5, 0.6, DisplacementMethod::DiamondSquare)
+            .with_edge(Edge::Left, 0.0)
+            .with_corner(Corner::BottomRight, 1.0);
+
+        let heightmap = generator.generate(&Seed::DEFAULT_SEED);
+
+        assert_eq!(heightmap.width(), 33);
+        assert_eq!(heightmap, generator.generate(&Seed::DEFAULT_SEED));
+        assert!((0..33).all(|y| heightmap[Point::new([0, y])] == 0.0));
+        assert_eq!(heightmap[Point::new([32, 32])], 1.0);
+        assert!(heightmap.values().iter().all(|value| value.is_finite()));
+    }
+
+    #[test]
+    fn roughness() {
+        for method in [DisplacementMethod::DiamondSquare, DisplacementMethod::Midpoint] {
+            let smooth = MidpointDisplacement::new(6, 0.3, method).generate(&Seed::DEFAULT_SEED);
+            let rough = MidpointDisplacement::new(6, 0.8, method).generate(&Seed::DEFAULT_SEED);
+
+            assert!(mean_gradient(&smooth) < mean_gradient(&rough));
+        };
+    }
+}
@@ -0,0 +1,17 @@
+mod climate;
+mod drainage;
+mod heightmap;
+mod heightmap_node;
+mod hydraulic_erosion;
+mod midpoint_displacement;
+mod spectral_synthesis;
+mod thermal_erosion;
+
+pub use climate::{Climate, ClimateMaps};
+pub use drainage::{fill_depressions, find_lakes, flow_directions, FlowField, FlowRouting, Lake};
+pub use heightmap::{EdgeMode, Heightmap, Interpolation};
+pub use heightmap_node::HeightmapNode;
+pub use hydraulic_erosion::{ErosionResult, HydraulicErosion};
+pub use midpoint_displacement::{Corner, DisplacementMethod, Edge, MidpointDisplacement};
+pub use spectral_synthesis::{PowerLawSpectrum, PowerSpectrum, spectral_synthesis};
+pub use thermal_erosion::ThermalErosion;
@@ -0,0 +1,160 @@
+use std::f64::consts::TAU;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use rand_distr::StandardNormal;
+use crate::{
+    analysis::{ifft_2d, Complex},
+    collections::Grid,
+    geometry::RealPoint,
+    random::{Seed, Seedable},
+    terrain::Heightmap,
+};
+
+pub trait PowerSpectrum {
+    // Spectral power at a frequency given in cycles per cell
+    fn power(&self, frequency: RealPoint<2>) -> f64;
+}
+
+impl<F> PowerSpectrum for F where F: Fn(RealPoint<2>) -> f64 {
+    fn power(&self, frequency: RealPoint<2>) -> f64 {
+        self(frequency)
+    }
+}
+
+// 1/f^beta spectrum. Frequencies are rotated by `angle` and then stretched along the rotated x axis
+// by `anisotropy`, so values above 1.0 elongate features along the rotated x axis.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct PowerLawSpectrum {
+    pub beta: f64,
+    pub anisotropy: f64,
+    pub angle: f64,
+}
+
+impl PowerLawSpectrum {
+    pub fn new(beta: f64) -> Self {
+        Self { beta, anisotropy: 1.0, angle: 0.0 }
+    }
+
+    pub fn with_anisotropy(self, anisotropy: f64, angle: f64) -> Self {
+        Self { anisotropy, angle, ..self }
+    }
+}
+
+impl PowerSpectrum for PowerLawSpectrum {
+    fn power(&self, frequency: RealPoint<2>) -> f64 {
+        let (sin, cos) = self.angle.sin_cos();
+        let u = (frequency[0] * cos + frequency[1] * sin) * self.anisotropy;
+        let v = frequency[1] * cos - frequency[0] * sin;
+        let magnitude = u.hypot(v);
+
+        if magnitude > 0.0 {
+            magnitude.powf(-self.beta)
+        } else {
+            0.0
+        }
+    }
+}
+
+// Builds a periodic heightfield by shaping Gaussian white noise with the given spectrum and taking
+// the inverse FFT. Both dimensions must be powers of two; the result is normalized onto [0.0, 1.0].
+pub fn spectral_synthesis<T>(seed: &Seed, width: usize, height: usize, spectrum: &T) -> Heightmap
+where T: PowerSpectrum {
+    let mut rng = ChaCha8Rng::from_seed(seed);
+
+    let mut data: Vec<Complex> = (0..(width * height)).map(|idx| {
+        let frequency = RealPoint::new([
+            signed_frequency(idx % width, width),
+            signed_frequency(idx / width, height),
+        ]);
+
+        let amplitude: f64 = rng.sample::<f64, _>(StandardNormal) * spectrum.power(frequency).sqrt();
+        let phase = rng.gen_range(0.0..TAU);
+
+        Complex::from_polar(amplitude, phase)
+    }).collect();
+
+    ifft_2d(&mut data, width, height);
+
+    let values = data.iter().map(|value| value.re).collect();
+    let mut heightmap = Heightmap::from(Grid::from_vec([width, height], values));
+
+    heightmap.normalize();
+    heightmap
+}
+
+fn signed_frequency(idx: usize, length: usize) -> f64 {
+    let idx = if idx <= length / 2 { idx as f64 } else { (idx as f64) - (length as f64) };
+
+    idx / (length as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        analysis::{RadialSpectrum, SampleRegion},
+        geometry::{Point, RealPoint},
+        noise::NoiseNode,
+        random::Seed,
+        terrain::{spectral_synthesis, EdgeMode, Heightmap, HeightmapNode, Interpolation, PowerLawSpectrum},
+    };
+
+    // Mean squared height difference between cells one step apart along `offset`, wrapping at the edges
+    fn roughness(heightmap: &Heightmap, offset: Point<2>) -> f64 {
+        let (width, height) = (heightmap.width() as i32, heightmap.height() as i32);
+
+        heightmap.cells().map(|cell| {
+            let other = Point::new([
+                (cell[0] + offset[0]).rem_euclid(width),
+                (cell[1] + offset[1]).rem_euclid(height),
+            ]);
+
+            (heightmap[cell] - heightmap[other]).powi(2)
+        }).sum::<f64>() / heightmap.cells().count() as f64
+    }
+
+    fn spectral_slope(beta: f64) -> f64 {
+        let heightmap = spectral_synthesis(&Seed::DEFAULT_SEED, 64, 64, &PowerLawSpectrum::new(beta));
+        let node = HeightmapNode::new(&heightmap, Interpolation::Nearest, EdgeMode::Wrap);
+        let spectrum = RadialSpectrum::measure(&node, &SampleRegion::new(RealPoint::new([0.0, 0.0]), 1.0, [64, 64]));
+
+        let (lo, hi) = (2usize, 16usize);
+
+        (spectrum.power()[hi] / spectrum.power()[lo]).ln() / ((hi as f64) / (lo as f64)).ln()
+    }
+
+    #[test]
+    fn power_law_slope() {
+        for beta in [1.0, 2.0, 3.0] {
+            let slope = spectral_slope(beta);
+
+            assert!((slope + beta).abs() < 0.6, "beta {}: slope {}", beta, slope);
+        };
+    }
+
+    #[test]
+    fn periodic() {
+        let heightmap = spectral_synthesis(&Seed::DEFAULT_SEED, 32, 16, &PowerLawSpectrum::new(2.0));
+        let node = HeightmapNode::new(&heightmap, Interpolation::Cubic, EdgeMode::Wrap);
+        let point = RealPoint::new([3.3, 7.6]);
+
+        assert_eq!(heightmap.min_max(), (0.0, 1.0));
+        assert!((node.value_at(point) - node.value_at(point + RealPoint::new([32.0, -16.0]))).abs() < 1e-12);
+    }
+
+    #[test]
+    fn anisotropy() {
+        let horizontal = PowerLawSpectrum::new(2.0).with_anisotropy(4.0, 0.0);
+        let vertical = horizontal.with_anisotropy(4.0, std::f64::consts::FRAC_PI_2);
+
+        for (spectrum, along, across) in [
+            (horizontal, Point::new([1, 0]), Point::new([0, 1])),
+            (vertical, Point::new([0, 1]), Point::new([1, 0])),
+        ] {
+            let heightmap = spectral_synthesis(&Seed::DEFAULT_SEED, 64, 64, &spectrum);
+            let (along, across) = (roughness(&heightmap, along), roughness(&heightmap, across));
+
+            // Features are elongated along the rotated x axis, so heights vary slowly in that direction
+            assert!(along * 2.0 < across, "{:?}: {} vs {}", spectrum, along, across);
+        };
+    }
+}
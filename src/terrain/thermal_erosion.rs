@@ -0,0 +1,98 @@
+use crate::{geometry::{Adjacency, EuclideanMetric, Point}, terrain::Heightmap};
+
+// Thermal weathering: wherever the slope to a neighbour exceeds the talus angle, a fraction of the
+// excess material slides down onto it. All cells are updated simultaneously, so the result does
+// not depend on iteration order.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ThermalErosion {
+    // Steepest stable slope, in radians
+    pub talus_angle: f64,
+    // Fraction of the excess height moved per iteration, in (0.0, 1.0]
+    pub rate: f64,
+    pub iterations: usize,
+    pub neighborhood: Adjacency,
+    // Horizontal distance between adjacent cells, in the same units as heights
+    pub cell_size: f64,
+}
+
+impl ThermalErosion {
+    pub fn new(talus_angle: f64, rate: f64, iterations: usize, neighborhood: Adjacency) -> Self {
+        Self { talus_angle, rate, iterations, neighborhood, cell_size: 1.0 }
+    }
+
+    pub fn erode(&self, heightmap: &Heightmap) -> Heightmap {
+        let mut heightmap = heightmap.clone();
+        let mut deltas = Heightmap::new(heightmap.width(), heightmap.height(), 0.0);
+
+        let max_slope = self.talus_angle.tan();
+
+        for _ in 0..self.iterations {
+            deltas.values_mut().fill(0.0);
+
+            for cell in heightmap.cells() {
+                let height = heightmap[cell];
+
+                let excesses: Vec<(Point<2>, f64)> = self.neighborhood.neighbors(cell).filter_map(|neighbor| {
+                    let neighbor_height = heightmap.get(neighbor)?;
+                    let distance = (neighbor - cell).to_real_point().magnitude::<EuclideanMetric>() * self.cell_size;
+                    let excess = height - neighbor_height - max_slope * distance;
+
+                    if excess > 0.0 { Some((neighbor, excess)) } else { None }
+                }).collect();
+
+                let total_excess: f64 = excesses.iter().map(|&(_, excess)| excess).sum();
+                let max_excess = excesses.iter().fold(0.0f64, |acc, &(_, excess)| acc.max(excess));
+
+                if total_excess <= 0.0 {
+                    continue;
+                };
+
+                // Moving half of the largest excess levels the steepest pair; spreading it in
+                // proportion to each neighbour's excess keeps the update stable
+                let amount = self.rate * max_excess / 2.0;
+
+                deltas[cell] -= amount;
+
+                for (neighbor, excess) in excesses {
+                    deltas[neighbor] += amount * excess / total_excess;
+                };
+            };
+
+            for (value, delta) in heightmap.values_mut().iter_mut().zip(deltas.values().iter()) {
+                *value += delta;
+            };
+        };
+
+        heightmap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{geometry::{Adjacency, Point}, terrain::{Heightmap, ThermalErosion}};
+
+    fn max_slope(heightmap: &Heightmap) -> f64 {
+        heightmap.cells().flat_map(|cell| {
+            Adjacency::VonNeumann.neighbors(cell).filter_map(move |neighbor| {
+                Some((heightmap[cell] - heightmap.get(neighbor)?).abs())
+            })
+        }).fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn erode() {
+        let spike = Heightmap::from_fn(9, 9, |cell| if cell == Point::new([4, 4]) { 10.0 } else { 0.0 });
+
+        for neighborhood in [Adjacency::Moore, Adjacency::VonNeumann] {
+            let erosion = ThermalErosion::new(0.5f64.atan(), 0.5, 200, neighborhood);
+            let result = erosion.erode(&spike);
+
+            let before: f64 = spike.values().iter().sum();
+            let after: f64 = result.values().iter().sum();
+
+            assert!((before - after).abs() < 1e-9);
+            assert_eq!(result, erosion.erode(&spike));
+            assert!(max_slope(&result) < 0.6, "{:?}: {}", neighborhood, max_slope(&result));
+        };
+    }
+}